@@ -0,0 +1,482 @@
+use {Error, Result, RetryPolicy, SheetId};
+use async_client::{BoxFuture, BoxStream};
+use client::{get_retry_after, is_idempotent, is_retryable, read_error};
+use dto::{ApiResult, IndexResult, Row, Sheet, SheetHeader};
+use futures::{Future, Stream};
+use futures::future::{self, Loop};
+use futures::stream;
+use pages::has_next_page;
+use reqwest::StatusCode;
+use reqwest::async::{Client as ReqwestClient, RequestBuilder};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{self, Value};
+use std::cmp;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio_timer::Delay;
+
+const DEFAULT_URL: &str = "https://api.smartsheet.com/2.0";
+const DEFAULT_PAGE_SIZE: u32 = 100;
+const QUERY_INCLUDE_OBJECT_VALUE: &[(&str, &str)] = &[("include", "objectValue"), ("level", "2")];
+
+// Retries are delayed with tokio timer, so futures must be run on tokio runtime
+#[derive(Clone, Debug)]
+pub struct Client {
+    http: ReqwestClient,
+    url: Arc<str>,
+    token: Arc<str>,
+    retry_policy: RetryPolicy,
+    page_size: u32,
+}
+
+impl Client {
+    pub fn new<T: Into<String>>(token: T) -> Self {
+        Self::new_with_url(DEFAULT_URL, token)
+    }
+
+    pub fn new_with_url<T: Into<String>, U: Into<String>>(url: T, token: U) -> Self {
+        Self {
+            http: ReqwestClient::new(),
+            url: url.into().into(),
+            token: token.into().into(),
+            retry_policy: RetryPolicy::default(),
+            page_size: DEFAULT_PAGE_SIZE,
+        }
+    }
+
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy,
+            ..self
+        }
+    }
+
+    pub fn with_page_size(self, page_size: u32) -> Self {
+        Self {
+            page_size: cmp::max(page_size, 1),
+            ..self
+        }
+    }
+
+    crate fn fetch_sheet(&self, id: &SheetId) -> BoxFuture<Sheet> {
+        let url = format!("{}/sheets/{}", self.url, id);
+        let future = self.fetch_json(false, move |http| http.get(&url).query(QUERY_INCLUDE_OBJECT_VALUE))
+            .map(|mut sheet: Sheet| {
+                sheet.interpret_cells();
+                sheet
+            });
+        Box::new(future)
+    }
+
+    pub fn fetch_sheets(&self) -> BoxFuture<Vec<SheetHeader>> {
+        let future = self.get_pages("sheets")
+            .fold(Vec::new(), |mut sheets, page| {
+                sheets.extend(page.into_data());
+                Ok::<_, Error>(sheets)
+            });
+        Box::new(future)
+    }
+
+    crate fn update_cell(&self, sheet_id: &SheetId, row: Row) -> BoxFuture<Vec<Row>> {
+        let url = format!("{}/sheets/{}/rows", self.url, sheet_id);
+        let future = self.fetch_json(false, move |http| http.put(&url).json(&row))
+            .map(|result: ApiResult<_>| result.result);
+        Box::new(future)
+    }
+
+    pub fn get_json<T: DeserializeOwned + Send + 'static>(&self, urn: &str) -> BoxFuture<T> {
+        let url = format!("{}/{}", self.url, urn);
+        self.fetch_json(false, move |http| http.get(&url))
+    }
+
+    // Works with any list endpoint, pages are fetched while polling
+    pub fn get_pages<T: DeserializeOwned + Send + 'static>(&self, urn: &str) -> BoxStream<IndexResult<T>> {
+        let client = self.clone();
+        let urn = urn.to_string();
+        let pages = stream::unfold(Some(1), move |next_page| {
+            let page_number = next_page?;
+            let page_size = client.page_size;
+            let future = client.fetch_page(&urn, page_number, page_size)
+                .map(move |page| {
+                    let next_page = if has_next_page(&page, page_number, page_size) {
+                        Some(page_number + 1)
+                    } else {
+                        None
+                    };
+                    (page, next_page)
+                });
+            Some(future)
+        });
+        Box::new(pages)
+    }
+
+    pub fn post_json<S, T>(&self, urn: &str, body: &S) -> BoxFuture<T>
+            where S: Serialize + ?Sized, T: DeserializeOwned + Send + 'static {
+        self.post_json_with(false, urn, body)
+    }
+
+    // Unlike post_json, failures are retried as for GET, so sending the request twice must be harmless
+    pub fn post_json_idempotent<S, T>(&self, urn: &str, body: &S) -> BoxFuture<T>
+            where S: Serialize + ?Sized, T: DeserializeOwned + Send + 'static {
+        self.post_json_with(true, urn, body)
+    }
+
+    fn post_json_with<S, T>(&self, is_repeatable: bool, urn: &str, body: &S) -> BoxFuture<T>
+            where S: Serialize + ?Sized, T: DeserializeOwned + Send + 'static {
+        // Body is serialized upfront, the request may be built multiple times
+        let body: Value = match serde_json::to_value(body) {
+            Ok(body) => body,
+            Err(error) => return Box::new(future::err(error.into())),
+        };
+        let url = format!("{}/{}", self.url, urn);
+        self.fetch_json(is_repeatable, move |http| http.post(&url).json(&body))
+    }
+
+    fn fetch_page<T: DeserializeOwned + Send + 'static>(&self, urn: &str, page_number: u32, page_size: u32)
+            -> BoxFuture<IndexResult<T>> {
+        let url = format!("{}/{}", self.url, urn);
+        self.fetch_json(false, move |http| http.get(&url).query(&[("page", page_number), ("pageSize", page_size)]))
+    }
+
+    // Same retry rules as in the blocking Client, the request is built again for every attempt
+    fn fetch_json<T, F>(&self, is_repeatable: bool, build_request: F) -> BoxFuture<T>
+            where T: DeserializeOwned + Send + 'static, F: Fn(&ReqwestClient) -> RequestBuilder + Send + 'static {
+        let client = self.clone();
+        let future = future::loop_fn(1, move |attempt| -> BoxFuture<Loop<T, u32>> {
+            let request = match build_request(&client.http).bearer_auth(&client.token).build() {
+                Ok(request) => request,
+                Err(error) => return Box::new(future::err(error.into())),
+            };
+            let is_repeatable = is_repeatable || is_idempotent(request.method());
+            let retry_policy = client.retry_policy.clone();
+            let future = client.http.execute(request)
+                .and_then(|response| {
+                    let status = response.status();
+                    let retry_after = get_retry_after(response.headers());
+                    response.into_body()
+                        .concat2()
+                        .map(move |body| (status, retry_after, body))
+                })
+                .then(move |response| -> BoxFuture<Loop<T, u32>> {
+                    let (result, delay) = match response {
+                        Ok((status, retry_after, body)) => {
+                            let result = parse_json(status, &body);
+                            let delay = if is_retryable(status, &result, is_repeatable) {
+                                Some(retry_policy.get_delay(attempt, retry_after))
+                            } else {
+                                None
+                            };
+                            (result, delay)
+                        },
+                        Err(error) => {
+                            let delay = if is_repeatable { Some(retry_policy.get_backoff(attempt)) } else { None };
+                            (Err(error.into()), delay)
+                        },
+                    };
+                    match delay.filter(|_| retry_policy.can_retry(attempt)) {
+                        Some(delay) => {
+                            let future = Delay::new(Instant::now() + delay)
+                                .map(move |()| Loop::Continue(attempt + 1))
+                                .map_err(|error| Error::Other(error.to_string()));
+                            Box::new(future)
+                        },
+                        None => Box::new(future::result(result.map(Loop::Break))),
+                    }
+                });
+            Box::new(future)
+        });
+        Box::new(future)
+    }
+}
+
+fn parse_json<T: DeserializeOwned>(status: StatusCode, body: &[u8]) -> Result<T> {
+    if !status.is_success() {
+        return Err(read_error(status, body))
+    }
+    Ok(serde_json::from_slice(body)?)
+}
+
+#[cfg(test)]
+impl Client {
+    pub fn new_mocked() -> Self {
+        Self::new_with_url(::mockito::SERVER_URL, "TEST_TOKEN")
+    }
+}
+
+#[cfg(test)]
+crate fn run<T: Send + 'static, E: Send + 'static>(future: Box<dyn Future<Item = T, Error = E> + Send>)
+        -> ::std::result::Result<T, E> {
+    ::tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(future)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ApiError;
+    use mockito::{self, Matcher, Mock};
+
+    mod client {
+        use super::*;
+
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        #[test]
+        fn is_send_and_sync() {
+            assert_send_sync::<Client>();
+        }
+    }
+
+    mod fetch_sheets {
+        use super::*;
+
+        #[test]
+        fn returns_all_sheets() {
+            let mock = mockito::mock("GET", "/sheets?page=1&pageSize=100")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "data": [
+                            {
+                                "id": 11,
+                                "name": "my_sheet"
+                            },
+                            {
+                                "id": 12,
+                                "name": "my_other_sheet"
+                            }
+                        ]
+                    }).to_string())
+                .create();
+            let client = Client::new_mocked();
+
+            let result = run(client.fetch_sheets());
+
+            mock.assert();
+            let actual = result.unwrap();
+            assert_eq!(2, actual.len());
+            assert_eq!("my_sheet", actual[0].get_name());
+            assert_eq!(SheetId::from(11), actual[0].get_sheet_id());
+            assert_eq!("my_other_sheet", actual[1].get_name());
+            assert_eq!(SheetId::from(12), actual[1].get_sheet_id());
+        }
+    }
+
+    mod get_json {
+        use super::*;
+
+        #[test]
+        fn returns_deserialized_body() {
+            let mock = mockito::mock("GET", "/new_route")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "result": null
+                    }).to_string())
+                .create();
+            let client = Client::new_mocked();
+
+            let result = run(client.get_json::<ApiResult<()>>("new_route"));
+
+            mock.assert();
+            result.unwrap();
+        }
+    }
+
+    mod get_pages {
+        use super::*;
+
+        fn mock_page(page_number: u32, names: &[&str]) -> Mock {
+            mockito::mock("GET", format!("/sheets?page={}&pageSize=2", page_number).as_str())
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "pageNumber": page_number,
+                        "totalPages": 2,
+                        "data": names.iter()
+                            .map(|name| json!({ "id": 11, "name": name }))
+                            .collect::<Vec<_>>()
+                    }).to_string())
+                .create()
+        }
+
+        #[test]
+        fn fetches_all_pages() {
+            let mock_first = mock_page(1, &["first", "second"]);
+            let mock_second = mock_page(2, &["third"]);
+            let client = Client::new_mocked().with_page_size(2);
+
+            let result = run(client.fetch_sheets());
+
+            mock_first.assert();
+            mock_second.assert();
+            let names: Vec<_> = result.unwrap()
+                .iter()
+                .map(|sheet| sheet.get_name().to_string())
+                .collect();
+            assert_eq!(vec!["first", "second", "third"], names);
+        }
+    }
+
+    mod post_json {
+        use super::*;
+
+        #[test]
+        fn returns_deserialized_body() {
+            let mock = mockito::mock("POST", "/new_route")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .match_header("content-type", "application/json")
+                .match_body(Matcher::Json(json!({
+                        "key": "value"
+                    })))
+                .with_body(json!({
+                        "result": null
+                    }).to_string())
+                .create();
+            let client = Client::new_mocked();
+
+            let result = run(client.post_json::<_, ApiResult<()>>("new_route", &json!({
+                    "key": "value"
+                })));
+
+            mock.assert();
+            result.unwrap();
+        }
+    }
+
+    mod fetch_json {
+        use super::*;
+        use serde_json::Value;
+
+        fn create_sheets_mock(with_status: usize, with_body: Value) -> Mock {
+            mockito::mock("GET", "/sheets?page=1&pageSize=100")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_status(with_status)
+                .with_body(with_body.to_string())
+                .create()
+        }
+
+        mod when_api_returns_error {
+            use super::*;
+
+            #[test]
+            fn then_returns_error() {
+                let mock = create_sheets_mock(500, json!({
+                        "errorCode": 4004,
+                        "message": "Test error"
+                    }));
+                let client = Client::new_mocked();
+
+                let result = run(client.fetch_sheets());
+
+                mock.assert();
+                let actual = result.unwrap_err();
+                let expected = Error::ServerError(ApiError::new(500, 4004, "Test error"));
+                assert_eq!(expected, actual);
+            }
+        }
+
+        mod when_api_returns_unexpected_json {
+            use super::*;
+
+            #[test]
+            fn then_returns_error() {
+                let mock = create_sheets_mock(200, json!({
+                        "Unexpected": "data"
+                    }));
+                let client = Client::new_mocked();
+
+                let result = run(client.fetch_sheets());
+
+                mock.assert();
+                let actual = result.unwrap_err();
+                let expected = Error::InvalidJson("missing field `data` at line 1 column 21".to_string());
+                assert_eq!(expected, actual);
+            }
+        }
+
+        mod when_api_returns_rate_limit_error {
+            use super::*;
+            use std::time::Duration;
+
+            #[test]
+            fn then_retries_until_max_attempts() {
+                let mock = mockito::mock("POST", "/new_route")
+                    .match_header("authorization", "Bearer TEST_TOKEN")
+                    .with_status(429)
+                    .with_body(json!({
+                            "errorCode": 4003,
+                            "message": "Rate limit exceeded."
+                        }).to_string())
+                    .expect(3)
+                    .create();
+                let client = Client::new_mocked()
+                    .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(1)));
+
+                let result = run(client.post_json::<_, ApiResult<()>>("new_route", &json!({})));
+
+                mock.assert();
+                let actual = result.unwrap_err();
+                let expected = Error::RateLimited(ApiError::new(429, 4003, "Rate limit exceeded."));
+                assert_eq!(expected, actual);
+            }
+        }
+
+        mod when_api_is_temporarily_unavailable {
+            use super::*;
+            use std::time::Duration;
+
+            fn create_unavailable_mock(hits: usize) -> Mock {
+                mockito::mock("POST", "/new_route")
+                    .with_status(500)
+                    .with_body(json!({
+                            "errorCode": 4001,
+                            "message": "System maintenance."
+                        }).to_string())
+                    .expect(hits)
+                    .create()
+            }
+
+            #[test]
+            fn then_does_not_retry_post() {
+                let mock = create_unavailable_mock(1);
+                let client = Client::new_mocked()
+                    .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(1)));
+
+                let result = run(client.post_json::<_, ApiResult<()>>("new_route", &json!({})));
+
+                mock.assert();
+                assert!(result.is_err());
+            }
+
+            #[test]
+            fn then_retries_idempotent_post() {
+                let mock = create_unavailable_mock(3);
+                let client = Client::new_mocked()
+                    .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(1)));
+
+                let result = run(client.post_json_idempotent::<_, ApiResult<()>>("new_route", &json!({})));
+
+                mock.assert();
+                assert!(result.is_err());
+            }
+        }
+
+        mod when_api_is_unreachable {
+            use super::*;
+
+            #[test]
+            fn then_returns_error() {
+                // Port 9 always discards all data
+                let client = Client::new_with_url("http://127.0.0.1:9/", "TEST_TOKEN");
+
+                let result = run(client.fetch_sheets());
+
+                let actual = result.unwrap_err();
+                match actual {
+                    Error::Network(_) => (),
+                    _ => panic!("Invalid error: '{:?}'", actual),
+                }
+            }
+        }
+    }
+}
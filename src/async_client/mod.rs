@@ -0,0 +1,13 @@
+mod client;
+mod smartsheet;
+
+pub use self::client::Client;
+pub use self::smartsheet::Smartsheet;
+
+use Error;
+use futures::{Future, Stream};
+
+pub type BoxFuture<T> = Box<dyn Future<Item = T, Error = Error> + Send>;
+pub type BoxStream<T> = Box<dyn Stream<Item = T, Error = Error> + Send>;
+// Failed write hands the Smartsheet back along with the error, so the loaded sheet isn't lost
+pub type PushFuture = Box<dyn Future<Item = Smartsheet, Error = (Error, Smartsheet)> + Send>;
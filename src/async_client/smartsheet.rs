@@ -0,0 +1,255 @@
+use {CellValue, ColumnId, Row, RowId, SheetId};
+use async_client::{BoxFuture, Client, PushFuture};
+use dto::{Cell, Sheet};
+use futures::Future;
+use smartsheet::find_sheet_id;
+
+#[derive(Debug)]
+pub struct Smartsheet {
+    client: Client,
+    sheet: Sheet,
+}
+
+impl Smartsheet {
+    pub fn fetch(client: &Client, sheet_name: &str) -> BoxFuture<Smartsheet> {
+        let client = client.clone();
+        let sheet_name = sheet_name.to_string();
+        let future = client.fetch_sheets()
+            .and_then(move |sheets| {
+                let sheet_id = find_sheet_id(&sheets, &sheet_name)?;
+                Ok(sheet_id)
+            })
+            .and_then(move |sheet_id| Self::fetch_by_id(&client, sheet_id));
+        Box::new(future)
+    }
+
+    pub fn fetch_by_id(client: &Client, sheet_id: SheetId) -> BoxFuture<Smartsheet> {
+        let client = client.clone();
+        let future = client.fetch_sheet(&sheet_id)
+            .map(move |sheet| Smartsheet {
+                client,
+                sheet,
+            });
+        Box::new(future)
+    }
+
+    pub fn get_column_id(&self, title: &str) -> Option<ColumnId> {
+        self.sheet.get_column_id(title)
+    }
+
+    pub fn find_row_id(&self, predicate: impl FnMut(&Row) -> bool) -> Option<RowId> {
+        self.sheet.find_row_id(predicate)
+    }
+
+    pub fn push_cell_value(mut self, column_id: &ColumnId, row_id: &RowId, cell_value: impl Into<CellValue>)
+            -> PushFuture {
+        let sheet_id = self.sheet.get_sheet_id();
+        let cell = Cell::new(column_id.clone(), cell_value.into());
+        let row = Row::new(row_id.clone(), cell);
+        let updated = self.client.update_cell(&sheet_id, row);
+        let future = updated.then(move |result| match result {
+            Ok(updated_rows) => {
+                self.sheet.update_rows(updated_rows);
+                Ok(self)
+            },
+            Err(error) => Err((error, self)),
+        });
+        Box::new(future)
+    }
+
+    pub fn get_cell_value(&self, column_id: &ColumnId, row_id: &RowId) -> Option<&CellValue> {
+        self.sheet.get_cell_value(column_id, row_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {ApiError, Error};
+    use async_client::client::run;
+    use mockito::{self, Matcher, Mock};
+
+    fn mock_sheets() -> Mock {
+        mockito::mock("GET", "/sheets?page=1&pageSize=100")
+            .match_header("authorization", "Bearer TEST_TOKEN")
+            .with_body(json!({
+                        "data": [
+                            {
+                                "id": 11,
+                                "name": "my_sheet"
+                            }
+                        ]
+                    }).to_string())
+            .create()
+    }
+
+    fn mock_sheet() -> Mock {
+        mockito::mock("GET", "/sheets/11?include=objectValue&level=2")
+            .match_header("authorization", "Bearer TEST_TOKEN")
+            .with_body(json!({
+                    "id": 11,
+                    "name": "my_sheet",
+                    "columns": [
+                        {
+                            "id": 21,
+                            "title": "my_column"
+                        },
+                        {
+                            "id": 22,
+                            "title": "other_column"
+                        }
+                    ],
+                    "rows": [
+                        {
+                            "id": 31,
+                            "cells": [
+                                {
+                                    "columnId": 21,
+                                    "value": "data_21_31"
+                                },
+                                {
+                                    "columnId": 22,
+                                    "value": "data_22_31"
+                                }
+                            ]
+                        },
+                        {
+                            "id": 32,
+                            "cells": [
+                                {
+                                    "columnId": 21,
+                                    "value": "data_21_32"
+                                }
+                            ]
+                        }
+                    ]
+                }).to_string())
+            .create()
+    }
+
+    fn create_smartsheet() -> Smartsheet {
+        let _mock_sheets = mock_sheets();
+        let _mock_sheet = mock_sheet();
+        let client = Client::new_mocked();
+        run(Smartsheet::fetch(&client, "my_sheet"))
+            .unwrap()
+    }
+
+    fn assert_cell_value(expected_str: &str, smartsheet: &Smartsheet, column_id: u64, row_id: u64) {
+        let assert_name = format!("for column {} and row {}", column_id, row_id);
+        let expected = &CellValue::from(expected_str);
+        let actual = smartsheet.get_cell_value(&ColumnId::from(column_id), &RowId::from(row_id))
+            .expect(&format!("Cell not found {}", assert_name));
+        assert_eq!(expected, actual, "Invalid cell value {}", assert_name);
+    }
+
+    mod fetch {
+        use super::*;
+
+        mod when_sheet_exists {
+            use super::*;
+
+            #[test]
+            fn then_returns_smartsheet() {
+                let mock_sheets = mock_sheets();
+                let mock_sheet = mock_sheet();
+                let client = Client::new_mocked();
+
+                let result = run(Smartsheet::fetch(&client, "my_sheet"));
+
+                mock_sheets.assert();
+                mock_sheet.assert();
+                let actual = result.unwrap();
+                assert_eq!("11", actual.sheet.get_sheet_id().to_string());
+            }
+        }
+
+        mod when_sheet_does_not_exist {
+            use super::*;
+
+            #[test]
+            fn then_returns_error() {
+                let mock = mock_sheets();
+                let client = Client::new_mocked();
+
+                let result = run(Smartsheet::fetch(&client, "nonexistent"));
+
+                mock.assert();
+                let actual = result.unwrap_err();
+                let expected = Error::InvalidSheetName("nonexistent".to_string());
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+
+    mod push_cell_value {
+        use super::*;
+
+        #[test]
+        fn updates_sheet() {
+            let smartsheet = create_smartsheet();
+            let mockito = mockito::mock("PUT", "/sheets/11/rows")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .match_header("content-type", "application/json")
+                .match_body(Matcher::Json(json!({
+                    "id":31,
+                    "cells": [
+                        {
+                            "columnId": 21,
+                            "value": "new_data"
+                        }
+                    ]
+                })))
+                .with_body(json!({
+                       "result": [
+                            {
+                                "id": 31,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "new_data"
+                                    },
+                                    {
+                                        "columnId": 22,
+                                        "value": "data_22_31"
+                                    }
+                                ]
+                            }
+                       ]
+                    }).to_string())
+                .create();
+            let column_id = &ColumnId::from(21);
+            let row_id = &RowId::from(31);
+            let cell_value = CellValue::from("new_data");
+
+            let result = run(smartsheet.push_cell_value(column_id, row_id, cell_value));
+
+            mockito.assert();
+            let smartsheet = result.unwrap();
+            assert_cell_value("new_data",   &smartsheet, 21, 31);
+            assert_cell_value("data_22_31", &smartsheet, 22, 31);
+            assert_cell_value("data_21_32", &smartsheet, 21, 32);
+        }
+
+        #[test]
+        fn returns_smartsheet_on_error() {
+            let smartsheet = create_smartsheet();
+            let mockito = mockito::mock("PUT", "/sheets/11/rows")
+                .with_status(400)
+                .with_body(json!({
+                        "errorCode": 1036,
+                        "message": "Invalid value"
+                    }).to_string())
+                .create();
+            let column_id = &ColumnId::from(21);
+            let row_id = &RowId::from(31);
+
+            let result = run(smartsheet.push_cell_value(column_id, row_id, "invalid"));
+
+            mockito.assert();
+            let (error, smartsheet) = result.unwrap_err();
+            assert_eq!(Error::Validation(ApiError::new(400, 1036, "Invalid value")), error);
+            assert_cell_value("data_21_31", &smartsheet, 21, 31);
+        }
+    }
+}
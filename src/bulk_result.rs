@@ -0,0 +1,89 @@
+use {Error, RowId};
+use dto::FailedItem;
+
+#[derive(Debug, PartialEq)]
+pub struct BulkResult<T> {
+    succeeded: Vec<T>,
+    failed: Vec<BulkFailure>,
+}
+
+impl<T> BulkResult<T> {
+    crate fn new() -> Self {
+        BulkResult {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        }
+    }
+
+    pub fn get_succeeded(&self) -> &[T] {
+        &self.succeeded
+    }
+
+    pub fn get_failed(&self) -> &[BulkFailure] {
+        &self.failed
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    pub fn into_succeeded(self) -> Vec<T> {
+        self.succeeded
+    }
+
+    crate fn add_succeeded(&mut self, succeeded: impl IntoIterator<Item = T>) {
+        self.succeeded.extend(succeeded);
+    }
+
+    crate fn add_failed(&mut self, failure: BulkFailure) {
+        self.failed.push(failure);
+    }
+
+    crate fn add_failed_items(&mut self, failed_items: Vec<FailedItem>, index_offset: usize) {
+        let failures = failed_items.into_iter()
+            .map(|item| BulkFailure {
+                index: item.get_index() + index_offset,
+                row_id: item.get_row_id(),
+                error: Error::from_failed_item(item.into_error()),
+            });
+        self.failed.extend(failures);
+    }
+
+    // Every item of a rejected request fails with the same error
+    crate fn add_failed_chunk(&mut self, row_ids: impl IntoIterator<Item = Option<RowId>>, index_offset: usize,
+            error: Error) {
+        let failures = row_ids.into_iter()
+            .enumerate()
+            .map(|(index, row_id)| BulkFailure::new(index + index_offset, row_id, error.clone()));
+        self.failed.extend(failures);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BulkFailure {
+    index: usize,
+    row_id: Option<RowId>,
+    error: Error,
+}
+
+impl BulkFailure {
+    crate fn new(index: usize, row_id: Option<RowId>, error: Error) -> Self {
+        BulkFailure {
+            index,
+            row_id,
+            error,
+        }
+    }
+
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    pub fn get_row_id(&self) -> Option<&RowId> {
+        self.row_id.as_ref()
+    }
+
+    pub fn get_error(&self) -> &Error {
+        &self.error
+    }
+}
@@ -1,18 +1,31 @@
-use {Result, SheetId};
-use dto::{ApiResult, Error, IndexResult, Row, Sheet, SheetHeader};
-use reqwest::{Client as ReqwestClient, RequestBuilder};
+use {ColumnId, Error, FolderId, Pages, Result, RetryPolicy, RowId, SheetId, SheetOptions, TransferOptions, WorkspaceId};
+use dto::{ApiResult, Column, ColumnUpdate, CopyOrMoveRowDirective, CopyOrMoveRowResult, Error as DtoError, Folder,
+          IndexResult, NewColumn, NewRow, Row, Sheet, SheetHeader, SheetVersion, SortCriterion, SortSpecifier};
+use reqwest::{Client as ReqwestClient, Method, RequestBuilder, Response, StatusCode};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use serde_json;
-use std::rc::Rc;
+use serde_json::Value;
+use std::cmp;
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 
 const DEFAULT_URL: &str = "https://api.smartsheet.com/2.0";
-const QUERY_DO_NOT_PAGINATE: &[(&str, &str)] = &[("includeAll", "true")];
+const DEFAULT_PAGE_SIZE: u32 = 100;
+const QUERY_ALLOW_PARTIAL_SUCCESS: &[(&str, &str)] = &[("allowPartialSuccess", "true")];
+const TRANSIENT_ERROR_CODES: &[u64] = &[4000, 4001, 4002];
 
 #[derive(Clone, Debug)]
 pub struct Client {
-    url: Rc<str>,
-    token: Rc<str>,
+    http: ReqwestClient,
+    url: Arc<str>,
+    token: Arc<str>,
+    retry_policy: RetryPolicy,
+    page_size: u32,
 }
 
 impl Client {
@@ -22,54 +35,295 @@ impl Client {
 
     pub fn new_with_url<T: Into<String>, U: Into<String>>(url: T, token: U) -> Self {
         Self {
+            http: ReqwestClient::new(),
             url: url.into().into(),
             token: token.into().into(),
+            retry_policy: RetryPolicy::default(),
+            page_size: DEFAULT_PAGE_SIZE,
         }
     }
 
-    crate fn fetch_sheet(&self, id: &SheetId) -> Result<Sheet> {
-        let builder = ReqwestClient::new()
-            .get(&format!("{}/sheets/{}", self.url, id));
-        self.fetch_json(builder)
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy,
+            ..self
+        }
+    }
+
+    pub fn with_page_size(self, page_size: u32) -> Self {
+        Self {
+            page_size: cmp::max(page_size, 1),
+            ..self
+        }
+    }
+
+    crate fn fetch_sheet(&self, id: &SheetId, options: &SheetOptions) -> Result<Sheet> {
+        let query = options.to_query();
+        let mut sheet: Sheet = self.fetch_json(|| self.http
+            .get(&format!("{}/sheets/{}", self.url, id))
+            .query(&query))?;
+        if options.is_partial() {
+            sheet.set_partial();
+        }
+        sheet.interpret_cells();
+        Ok(sheet)
+    }
+
+    crate fn fetch_sheet_version(&self, id: &SheetId) -> Result<u64> {
+        let version: SheetVersion = self.fetch_json(|| self.http
+            .get(&format!("{}/sheets/{}/version", self.url, id)))?;
+        Ok(version.get_version())
     }
 
     pub fn fetch_sheets(&self) -> Result<Vec<SheetHeader>> {
-        let builder = ReqwestClient::new()
-            .get(&format!("{}/sheets", self.url))
-            .query(QUERY_DO_NOT_PAGINATE);
-        let result: IndexResult<_> = self.fetch_json(builder)?;
-        Ok(result.into_data())
+        self.get_pages("sheets")
+            .fetch_all()
+    }
+
+    pub fn fetch_workspace_sheets(&self, workspace_id: &WorkspaceId) -> Result<Vec<SheetHeader>> {
+        let workspace: Folder = self.fetch_json(|| self.http
+            .get(&format!("{}/workspaces/{}", self.url, workspace_id)))?;
+        Ok(workspace.into_sheets())
+    }
+
+    pub fn fetch_folder_sheets(&self, folder_id: &FolderId) -> Result<Vec<SheetHeader>> {
+        let folder: Folder = self.fetch_json(|| self.http
+            .get(&format!("{}/folders/{}", self.url, folder_id)))?;
+        Ok(folder.into_sheets())
     }
 
-    crate fn update_cell(&self, sheet_id: &SheetId, row: Row) -> Result<Vec<Row>> {
-        let builder = ReqwestClient::new()
+    crate fn update_cell(&self, sheet_id: &SheetId, row: Row) -> Result<ApiResult<Vec<Row>>> {
+        self.fetch_json(|| self.http
             .put(&format!("{}/sheets/{}/rows", self.url, sheet_id))
-            .json(&row);
-        let result: ApiResult<_> = self.fetch_json(builder)?;
-        Ok(result.result)
+            .json(&row))
+    }
+
+    crate fn update_rows<T: Serialize>(&self, sheet_id: &SheetId, rows: &[T], allow_partial_success: bool)
+            -> Result<ApiResult<Vec<Row>>> {
+        self.fetch_json(|| {
+            let builder = self.http
+                .put(&format!("{}/sheets/{}/rows", self.url, sheet_id))
+                .json(rows);
+            with_partial_success(builder, allow_partial_success)
+        })
+    }
+
+    crate fn add_rows(&self, sheet_id: &SheetId, rows: &[NewRow], allow_partial_success: bool)
+            -> Result<ApiResult<Vec<Row>>> {
+        self.fetch_json(|| {
+            let builder = self.http
+                .post(&format!("{}/sheets/{}/rows", self.url, sheet_id))
+                .json(rows);
+            with_partial_success(builder, allow_partial_success)
+        })
+    }
+
+    crate fn delete_rows(&self, sheet_id: &SheetId, row_ids: &[RowId], ignore_rows_not_found: bool)
+            -> Result<ApiResult<Vec<RowId>>> {
+        let ids = join_row_ids(row_ids);
+        let ignore_rows_not_found = ignore_rows_not_found.to_string();
+        self.fetch_json(|| self.http
+            .delete(&format!("{}/sheets/{}/rows", self.url, sheet_id))
+            .query(&[("ids", &ids), ("ignoreRowsNotFound", &ignore_rows_not_found)]))
+    }
+
+    crate fn fetch_rows(&self, sheet_id: &SheetId, row_ids: &[RowId]) -> Result<Vec<Row>> {
+        let options = SheetOptions::new()
+            .with_row_ids(row_ids.to_vec());
+        let sheet = self.fetch_sheet(sheet_id, &options)?;
+        Ok(sheet.into_rows())
+    }
+
+    crate fn move_rows(&self, sheet_id: &SheetId, row_ids: &[RowId], destination_id: &SheetId, options: &TransferOptions)
+            -> Result<BTreeMap<RowId, RowId>> {
+        self.transfer_rows("move", sheet_id, row_ids, destination_id, &options.get_include(false))
+    }
+
+    crate fn copy_rows(&self, sheet_id: &SheetId, row_ids: &[RowId], destination_id: &SheetId, options: &TransferOptions)
+            -> Result<BTreeMap<RowId, RowId>> {
+        self.transfer_rows("copy", sheet_id, row_ids, destination_id, &options.get_include(true))
+    }
+
+    fn transfer_rows(&self, operation: &str, sheet_id: &SheetId, row_ids: &[RowId], destination_id: &SheetId, include: &str)
+            -> Result<BTreeMap<RowId, RowId>> {
+        let directive = CopyOrMoveRowDirective::new(row_ids, destination_id.clone());
+        let result: CopyOrMoveRowResult = self.fetch_json(|| {
+            let builder = self.http
+                .post(&format!("{}/sheets/{}/rows/{}", self.url, sheet_id, operation))
+                .json(&directive);
+            if include.is_empty() {
+                builder
+            } else {
+                builder.query(&[("include", include)])
+            }
+        })?;
+        Ok(result.into_row_mappings())
+    }
+
+    crate fn sort_rows(&self, sheet_id: &SheetId, sort_criteria: &[SortCriterion]) -> Result<Sheet> {
+        let query = SheetOptions::new().to_query();
+        let sort_specifier = SortSpecifier::new(sort_criteria);
+        // Sorting again gives the same order
+        let mut sheet: Sheet = self.fetch_json_repeatable(|| self.http
+            .post(&format!("{}/sheets/{}/sort", self.url, sheet_id))
+            .query(&query)
+            .json(&sort_specifier))?;
+        sheet.interpret_cells();
+        Ok(sheet)
+    }
+
+    crate fn fetch_columns(&self, sheet_id: &SheetId) -> Result<Vec<Column>> {
+        self.get_pages(&format!("sheets/{}/columns", sheet_id))
+            .fetch_all()
+    }
+
+    crate fn add_columns(&self, sheet_id: &SheetId, columns: &[NewColumn]) -> Result<ApiResult<Vec<Column>>> {
+        self.fetch_json(|| self.http
+            .post(&format!("{}/sheets/{}/columns", self.url, sheet_id))
+            .json(columns))
+    }
+
+    crate fn update_column(&self, sheet_id: &SheetId, column_id: &ColumnId, update: &ColumnUpdate)
+            -> Result<ApiResult<Column>> {
+        self.fetch_json(|| self.http
+            .put(&format!("{}/sheets/{}/columns/{}", self.url, sheet_id, column_id))
+            .json(update))
+    }
+
+    crate fn delete_column(&self, sheet_id: &SheetId, column_id: &ColumnId) -> Result<ApiResult<Option<Value>>> {
+        self.fetch_json(|| self.http
+            .delete(&format!("{}/sheets/{}/columns/{}", self.url, sheet_id, column_id)))
     }
 
     pub fn get_json<T: DeserializeOwned>(&self, urn: &str) -> Result<T> {
-        let builder = ReqwestClient::new()
-            .get(&format!("{}/{}", self.url, urn));
-        self.fetch_json(builder)
+        self.fetch_json(|| self.http
+            .get(&format!("{}/{}", self.url, urn)))
+    }
+
+    // Works with any list endpoint, pages are fetched while iterating
+    pub fn get_pages<T: DeserializeOwned>(&self, urn: &str) -> Pages<T> {
+        Pages::new(self.clone(), urn.to_string(), self.page_size)
     }
 
     pub fn post_json<S: Serialize + ?Sized, T: DeserializeOwned>(&self, urn: &str, body: &S) -> Result<T> {
-        let builder = ReqwestClient::new()
+        self.fetch_json(|| self.http
             .post(&format!("{}/{}", self.url, urn))
-            .json(body);
-        self.fetch_json(builder)
+            .json(body))
+    }
+
+    // Unlike post_json, failures are retried as for GET, so sending the request twice must be harmless
+    pub fn post_json_idempotent<S: Serialize + ?Sized, T: DeserializeOwned>(&self, urn: &str, body: &S) -> Result<T> {
+        self.fetch_json_repeatable(|| self.http
+            .post(&format!("{}/{}", self.url, urn))
+            .json(body))
+    }
+
+    crate fn fetch_page<T: DeserializeOwned>(&self, urn: &str, page_number: u32, page_size: u32)
+            -> Result<IndexResult<T>> {
+        let page_number = page_number.to_string();
+        let page_size = page_size.to_string();
+        self.fetch_json(|| self.http
+            .get(&format!("{}/{}", self.url, urn))
+            .query(&[("page", &page_number), ("pageSize", &page_size)]))
+    }
+
+    // Rate limited requests are always retried, failed ones only if they are idempotent
+    fn fetch_json<T: DeserializeOwned>(&self, build_request: impl Fn() -> RequestBuilder) -> Result<T> {
+        self.send_json(false, build_request)
+    }
+
+    // For requests which are harmless to repeat regardless of the method
+    fn fetch_json_repeatable<T: DeserializeOwned>(&self, build_request: impl Fn() -> RequestBuilder) -> Result<T> {
+        self.send_json(true, build_request)
     }
 
-    fn fetch_json<T: DeserializeOwned>(&self, builder: RequestBuilder) -> Result<T> {
-        let response = builder.bearer_auth(&self.token)
-            .send()?;
-        if !response.status().is_success() {
-            let error: Error = serde_json::from_reader(response)?;
-            return Err(error.into())
+    fn send_json<T: DeserializeOwned>(&self, is_repeatable: bool, build_request: impl Fn() -> RequestBuilder)
+            -> Result<T> {
+        let mut attempt = 1;
+        loop {
+            let request = build_request()
+                .bearer_auth(&self.token)
+                .build()?;
+            let is_repeatable = is_repeatable || is_idempotent(request.method());
+            let response = match self.http.execute(request) {
+                Ok(response) => response,
+                Err(error) => {
+                    if !is_repeatable || !self.retry_policy.can_retry(attempt) {
+                        return Err(error.into())
+                    }
+                    thread::sleep(self.retry_policy.get_backoff(attempt));
+                    attempt += 1;
+                    continue
+                },
+            };
+            let status = response.status();
+            let retry_after = get_retry_after(response.headers());
+            let result = read_json(response);
+            if !is_retryable(status, &result, is_repeatable) || !self.retry_policy.can_retry(attempt) {
+                return result
+            }
+            thread::sleep(self.retry_policy.get_delay(attempt, retry_after));
+            attempt += 1;
         }
-        Ok(serde_json::from_reader(response)?)
+    }
+}
+
+fn join_row_ids(row_ids: &[RowId]) -> String {
+    row_ids.iter()
+        .map(RowId::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn with_partial_success(builder: RequestBuilder, allow_partial_success: bool) -> RequestBuilder {
+    if allow_partial_success {
+        builder.query(QUERY_ALLOW_PARTIAL_SUCCESS)
+    } else {
+        builder
+    }
+}
+
+fn read_json<T: DeserializeOwned>(mut response: Response) -> Result<T> {
+    let status = response.status();
+    if !status.is_success() {
+        let mut body = Vec::new();
+        response.read_to_end(&mut body)
+            .map_err(|error| Error::Network(error.to_string()))?;
+        return Err(read_error(status, &body))
+    }
+    Ok(serde_json::from_reader(response)?)
+}
+
+// Proxies and load balancers may respond with a body which isn't Smartsheet error
+crate fn read_error(status: StatusCode, body: &[u8]) -> Error {
+    match serde_json::from_slice::<DtoError>(body) {
+        Ok(error) => Error::from_response(status.as_u16(), error),
+        Err(_) => Error::from_status(status.as_u16(), status.canonical_reason().unwrap_or("Unknown error")),
+    }
+}
+
+crate fn get_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let seconds = headers.get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+crate fn is_idempotent(method: &Method) -> bool {
+    *method == Method::GET || *method == Method::HEAD || *method == Method::PUT || *method == Method::DELETE
+}
+
+// Rate limited request wasn't executed, so it can be retried regardless of the method
+crate fn is_retryable<T>(status: StatusCode, result: &Result<T>, is_repeatable: bool) -> bool {
+    match *result {
+        Ok(_) => false,
+        Err(Error::RateLimited(_)) => true,
+        Err(Error::ServerError(ref error)) if error.get_code().map_or(false, |code| TRANSIENT_ERROR_CODES.contains(&code)) =>
+            is_repeatable,
+        Err(_) => status == StatusCode::TOO_MANY_REQUESTS || (is_repeatable && status == StatusCode::SERVICE_UNAVAILABLE),
     }
 }
 
@@ -83,15 +337,51 @@ impl Client {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use Error as ResError;
+    use ApiError;
     use mockito::{self, Matcher, Mock};
+    use std::time::Instant;
+
+    mod client {
+        use super::*;
+
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        #[test]
+        fn is_send_and_sync() {
+            assert_send_sync::<Client>();
+        }
+
+        #[test]
+        fn clones_can_be_used_from_other_threads() {
+            let mock = mockito::mock("GET", "/sheets?page=1&pageSize=100")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "data": []
+                    }).to_string())
+                .expect(2)
+                .create();
+            let client = Client::new_mocked();
+
+            let handles: Vec<_> = (0..2)
+                .map(|_| {
+                    let client = client.clone();
+                    ::std::thread::spawn(move || client.fetch_sheets())
+                })
+                .collect();
+
+            for handle in handles {
+                handle.join().unwrap().unwrap();
+            }
+            mock.assert();
+        }
+    }
 
     mod fetch_sheets {
         use super::*;
 
         #[test]
         fn returns_all_sheets() {
-            let mock = mockito::mock("GET", "/sheets?includeAll=true")
+            let mock = mockito::mock("GET", "/sheets?page=1&pageSize=100")
                 .match_header("authorization", "Bearer TEST_TOKEN")
                 .with_body(json!({
                         "data": [
@@ -140,6 +430,82 @@ mod tests {
         }
     }
 
+    mod get_pages {
+        use super::*;
+
+        fn mock_page(page_number: u32, names: &[&str]) -> Mock {
+            mockito::mock("GET", format!("/sheets?page={}&pageSize=2", page_number).as_str())
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "pageNumber": page_number,
+                        "pageSize": 2,
+                        "totalPages": 2,
+                        "totalCount": 3,
+                        "data": names.iter()
+                            .map(|name| json!({ "id": 11, "name": name }))
+                            .collect::<Vec<_>>()
+                    }).to_string())
+        }
+
+        fn mock_page_without_total(page_number: u32, names: &[&str]) -> Mock {
+            mockito::mock("GET", format!("/sheets?page={}&pageSize=2", page_number).as_str())
+                .with_body(json!({
+                        "data": names.iter()
+                            .map(|name| json!({ "id": 11, "name": name }))
+                            .collect::<Vec<_>>()
+                    }).to_string())
+                .create()
+        }
+
+        #[test]
+        fn fetches_pages_on_demand() {
+            let mock_first = mock_page(1, &["first", "second"]).create();
+            let mock_second = mock_page(2, &["third"]).expect(0).create();
+            let client = Client::new_mocked().with_page_size(2);
+
+            let mut pages = client.get_pages::<SheetHeader>("sheets");
+            let actual = pages.next().unwrap().unwrap();
+
+            mock_first.assert();
+            mock_second.assert();
+            assert_eq!(Some(1), actual.get_page_number());
+            assert_eq!(Some(2), actual.get_page_size());
+            assert_eq!(Some(2), actual.get_total_pages());
+            assert_eq!(Some(3), actual.get_total_count());
+            assert_eq!(2, actual.get_data().len());
+        }
+
+        #[test]
+        fn stops_after_last_page() {
+            let mock_first = mock_page(1, &["first", "second"]).create();
+            let mock_second = mock_page(2, &["third"]).create();
+            let client = Client::new_mocked().with_page_size(2);
+
+            let actual = client.fetch_sheets();
+
+            mock_first.assert();
+            mock_second.assert();
+            let names: Vec<_> = actual.unwrap()
+                .iter()
+                .map(|sheet| sheet.get_name().to_string())
+                .collect();
+            assert_eq!(vec!["first", "second", "third"], names);
+        }
+
+        #[test]
+        fn continues_while_pages_are_full_without_total_pages() {
+            let mock_first = mock_page_without_total(1, &["first", "second"]);
+            let mock_second = mock_page_without_total(2, &["third"]);
+            let client = Client::new_mocked().with_page_size(2);
+
+            let actual = client.fetch_sheets();
+
+            mock_first.assert();
+            mock_second.assert();
+            assert_eq!(3, actual.unwrap().len());
+        }
+    }
+
     mod post_json {
         use super::*;
 
@@ -171,7 +537,7 @@ mod tests {
         use serde_json::Value;
 
         fn create_sheets_mock(with_status: usize, with_body: Value) -> Mock {
-            mockito::mock("GET", "/sheets?includeAll=true")
+            mockito::mock("GET", "/sheets?page=1&pageSize=100")
                 .match_header("authorization", "Bearer TEST_TOKEN")
                 .with_status(with_status)
                 .with_body(with_body.to_string())
@@ -215,10 +581,7 @@ mod tests {
 
                 mock.assert();
                 let actual = result.unwrap_err();
-                let expected = ResError::SmartsheetOther {
-                    code: 4004,
-                    message: "Test error".to_string(),
-                };
+                let expected = Error::ServerError(ApiError::new(500, 4004, "Test error"));
                 assert_eq!(expected, actual);
             }
         }
@@ -237,9 +600,170 @@ mod tests {
 
                 mock.assert();
                 let actual = result.unwrap_err();
-                let expected = ResError::InvalidJson("missing field `data` at line 1 column 21".to_string());
+                let expected = Error::InvalidJson("missing field `data` at line 1 column 21".to_string());
+                assert_eq!(expected, actual);
+            }
+        }
+
+        mod when_api_returns_error_without_json {
+            use super::*;
+
+            #[test]
+            fn then_returns_error_for_status() {
+                let mock = mockito::mock("GET", "/sheets?page=1&pageSize=100")
+                    .with_status(404)
+                    .with_body("<html><body>Not Found</body></html>")
+                    .create();
+                let client = Client::new_mocked();
+
+                let result = client.fetch_sheets();
+
+                mock.assert();
+                assert_eq!(Error::from_status(404, "Not Found"), result.unwrap_err());
+            }
+        }
+
+        mod when_api_returns_rate_limit_error {
+            use super::*;
+
+            fn create_rate_limit_mock(hits: usize) -> Mock {
+                mockito::mock("GET", "/sheets?page=1&pageSize=100")
+                    .match_header("authorization", "Bearer TEST_TOKEN")
+                    .with_status(429)
+                    .with_body(json!({
+                            "errorCode": 4003,
+                            "message": "Rate limit exceeded."
+                        }).to_string())
+                    .expect(hits)
+                    .create()
+            }
+
+            #[test]
+            fn then_retries_until_max_attempts() {
+                let mock = create_rate_limit_mock(3);
+                let client = Client::new_mocked()
+                    .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(1)));
+
+                let result = client.fetch_sheets();
+
+                mock.assert();
+                let actual = result.unwrap_err();
+                let expected = Error::RateLimited(ApiError::new(429, 4003, "Rate limit exceeded."));
                 assert_eq!(expected, actual);
             }
+
+            #[test]
+            fn then_retries_post() {
+                let mock = mockito::mock("POST", "/new_route")
+                    .with_status(429)
+                    .with_body(json!({
+                            "errorCode": 4003,
+                            "message": "Rate limit exceeded."
+                        }).to_string())
+                    .expect(3)
+                    .create();
+                let client = Client::new_mocked()
+                    .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(1)));
+
+                let result = client.post_json::<_, ApiResult<()>>("new_route", &json!({}));
+
+                mock.assert();
+                assert!(result.is_err());
+            }
+        }
+
+        mod when_api_is_temporarily_unavailable {
+            use super::*;
+
+            #[test]
+            fn then_retries_and_returns_dto() {
+                let mock_unavailable = mockito::mock("GET", "/sheets?page=1&pageSize=100")
+                    .with_status(503)
+                    .with_body(json!({
+                            "errorCode": 4001,
+                            "message": "Smartsheet.com is currently offline for system maintenance."
+                        }).to_string())
+                    .expect(1)
+                    .create();
+                let mock_available = create_sheets_mock(200, json!({
+                        "data": []
+                    }));
+                let client = Client::new_mocked()
+                    .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(1)));
+
+                let result = client.fetch_sheets();
+
+                mock_unavailable.assert();
+                mock_available.assert();
+                assert!(result.is_ok());
+            }
+
+            #[test]
+            fn then_waits_as_long_as_retry_after_header_says() {
+                let mock = mockito::mock("GET", "/sheets?page=1&pageSize=100")
+                    .with_status(503)
+                    .with_header("retry-after", "0")
+                    .with_body("Service Unavailable")
+                    .expect(2)
+                    .create();
+                let client = Client::new_mocked()
+                    .with_retry_policy(RetryPolicy::new(2, Duration::from_secs(3600), Duration::from_secs(3600)));
+
+                let result = client.fetch_sheets();
+
+                mock.assert();
+                assert_eq!(Error::from_status(503, "Service Unavailable"), result.unwrap_err());
+            }
+
+            #[test]
+            fn then_does_not_retry_post() {
+                let mock = mockito::mock("POST", "/new_route")
+                    .with_status(503)
+                    .with_body("Service Unavailable")
+                    .expect(1)
+                    .create();
+                let client = Client::new_mocked()
+                    .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(1)));
+
+                let result = client.post_json::<_, ApiResult<()>>("new_route", &json!({}));
+
+                mock.assert();
+                assert!(result.is_err());
+            }
+
+            #[test]
+            fn then_retries_idempotent_post() {
+                let mock = mockito::mock("POST", "/new_route")
+                    .with_status(503)
+                    .with_body("Service Unavailable")
+                    .expect(3)
+                    .create();
+                let client = Client::new_mocked()
+                    .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(1)));
+
+                let result = client.post_json_idempotent::<_, ApiResult<()>>("new_route", &json!({}));
+
+                mock.assert();
+                assert!(result.is_err());
+            }
+
+            #[test]
+            fn then_waits_no_longer_than_max_retry_after() {
+                let mock = mockito::mock("GET", "/sheets?page=1&pageSize=100")
+                    .with_status(503)
+                    .with_header("retry-after", "3600")
+                    .with_body("Service Unavailable")
+                    .expect(2)
+                    .create();
+                let client = Client::new_mocked()
+                    .with_retry_policy(RetryPolicy::new(2, Duration::from_millis(1), Duration::from_millis(1))
+                        .with_max_retry_after(Duration::from_millis(1)));
+
+                let result = client.fetch_sheets();
+
+                mock.assert();
+                assert!(result.is_err());
+            }
         }
 
         mod when_api_is_unreachable {
@@ -254,10 +778,36 @@ mod tests {
 
                 let actual = result.unwrap_err();
                 match actual {
-                    ResError::Network(_) => (),
+                    Error::Network(_) => (),
                     _ => panic!("Invalid error: '{:?}'", actual),
                 }
             }
+
+            #[test]
+            fn then_retries_get() {
+                let client = Client::new_with_url("http://127.0.0.1:9/", "TEST_TOKEN")
+                    .with_retry_policy(RetryPolicy::new(3, Duration::from_millis(100), Duration::from_millis(100)));
+                let started = Instant::now();
+
+                let result = client.fetch_sheets();
+
+                assert!(result.is_err());
+                // Two backoffs of at least half of 100 ms each
+                assert!(started.elapsed() >= Duration::from_millis(100), "Retried too early: {:?}", started.elapsed());
+            }
+
+            #[test]
+            fn then_does_not_retry_post() {
+                let client = Client::new_with_url("http://127.0.0.1:9/", "TEST_TOKEN")
+                    .with_retry_policy(RetryPolicy::new(3, Duration::from_secs(3600), Duration::from_secs(3600)));
+
+                let result = client.post_json::<_, ApiResult<()>>("new_route", &json!({}));
+
+                match result.unwrap_err() {
+                    Error::Network(_) => (),
+                    error => panic!("Invalid error: '{:?}'", error),
+                }
+            }
         }
     }
 }
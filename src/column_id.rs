@@ -1,3 +1,5 @@
+use std::fmt::{Display, Error as FmtError, Formatter};
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(transparent)]
 pub struct ColumnId {
@@ -9,3 +11,9 @@ impl From<u64> for ColumnId {
         ColumnId { id }
     }
 }
+
+impl Display for ColumnId {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        self.id.fmt(f)
+    }
+}
@@ -1,9 +1,15 @@
+use dto::FailedItem;
 use serde::de::DeserializeOwned;
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ApiResult<T: DeserializeOwned> {
     // Annotation required because of https://github.com/serde-rs/serde/issues/1296
     // Remove when https://github.com/rust-lang/rust/issues/41617 is fixed
     #[serde(bound = "")]
     pub result: T,
+    #[serde(default)]
+    pub failed_items: Vec<FailedItem>,
+    // New sheet version after a write
+    pub version: Option<u64>,
 }
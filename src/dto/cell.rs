@@ -1,16 +1,75 @@
 use {CellValue, ColumnId};
+use dto::{CellLink, ColumnType, Hyperlink, Image, ObjectValue};
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeStruct;
+use serde_json::{self, Value};
+use std::mem;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Cell {
     column_id: ColumnId,
     #[serde(default)]
     value: CellValue,
+    object_value: Option<Value>,
+    display_value: Option<String>,
+    formula: Option<String>,
+    hyperlink: Option<Hyperlink>,
+    link_in_from_cell: Option<CellLink>,
+    #[serde(default)]
+    links_out_to_cells: Vec<CellLink>,
+    format: Option<String>,
+    conditional_format: Option<String>,
+    image: Option<Image>,
+    #[serde(skip)]
+    formula_overwrite_allowed: bool,
 }
 
 impl Cell {
     pub fn new(column_id: ColumnId, value: CellValue) -> Cell {
-        Cell { column_id, value, }
+        Cell {
+            column_id,
+            value,
+            object_value: None,
+            display_value: None,
+            formula: None,
+            hyperlink: None,
+            link_in_from_cell: None,
+            links_out_to_cells: Vec::new(),
+            format: None,
+            conditional_format: None,
+            image: None,
+            formula_overwrite_allowed: false,
+        }
+    }
+
+    pub fn new_formula<T: Into<String>>(column_id: ColumnId, formula: T) -> Cell {
+        Cell {
+            formula: Some(formula.into()),
+            ..Cell::new(column_id, CellValue::Empty)
+        }
+    }
+
+    pub fn new_link(column_id: ColumnId, link_in_from_cell: CellLink) -> Cell {
+        Cell {
+            link_in_from_cell: Some(link_in_from_cell),
+            ..Cell::new(column_id, CellValue::Empty)
+        }
+    }
+
+    pub fn with_hyperlink(self, hyperlink: Hyperlink) -> Cell {
+        Cell {
+            hyperlink: Some(hyperlink),
+            ..self
+        }
+    }
+
+    // Without this writing a value into a cell containing formula is rejected
+    pub fn allow_formula_overwrite(self) -> Cell {
+        Cell {
+            formula_overwrite_allowed: true,
+            ..self
+        }
     }
 
     pub fn get_column_id(&self) -> ColumnId {
@@ -20,4 +79,76 @@ impl Cell {
     pub fn get_value(&self) -> &CellValue {
         &self.value
     }
+
+    pub fn get_object_value(&self) -> Option<&Value> {
+        self.object_value.as_ref()
+    }
+
+    pub fn get_display_value(&self) -> Option<&str> {
+        self.display_value.as_ref().map(String::as_str)
+    }
+
+    pub fn get_formula(&self) -> Option<&str> {
+        self.formula.as_ref().map(String::as_str)
+    }
+
+    pub fn get_hyperlink(&self) -> Option<&Hyperlink> {
+        self.hyperlink.as_ref()
+    }
+
+    pub fn get_link_in_from_cell(&self) -> Option<&CellLink> {
+        self.link_in_from_cell.as_ref()
+    }
+
+    pub fn get_links_out_to_cells(&self) -> &[CellLink] {
+        &self.links_out_to_cells
+    }
+
+    pub fn get_format(&self) -> Option<&str> {
+        self.format.as_ref().map(String::as_str)
+    }
+
+    pub fn get_conditional_format(&self) -> Option<&str> {
+        self.conditional_format.as_ref().map(String::as_str)
+    }
+
+    pub fn get_image(&self) -> Option<&Image> {
+        self.image.as_ref()
+    }
+
+    pub fn is_formula_overwrite_allowed(&self) -> bool {
+        self.formula_overwrite_allowed
+    }
+
+    crate fn interpret(&mut self, column_type: &ColumnType) {
+        let object_value = self.object_value.as_ref()
+            .and_then(|object_value| serde_json::from_value::<ObjectValue>(object_value.clone()).ok());
+        let value = mem::replace(&mut self.value, CellValue::Empty);
+        self.value = match object_value {
+            Some(object_value) => object_value.into_cell_value(),
+            None => value.interpret(column_type),
+        };
+    }
+}
+
+// Only writable fields are serialized, formula or link replaces the value
+impl Serialize for Cell {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Cell", 4)?;
+        state.serialize_field("columnId", &self.column_id)?;
+        if let Some(ref formula) = self.formula {
+            state.serialize_field("formula", formula)?;
+        } else if let Some(ref link_in_from_cell) = self.link_in_from_cell {
+            state.serialize_field("value", &CellValue::Empty)?;
+            state.serialize_field("linkInFromCell", link_in_from_cell)?;
+        } else if let Some(object_value) = ObjectValue::from_cell_value(&self.value) {
+            state.serialize_field("objectValue", &object_value)?;
+        } else {
+            state.serialize_field("value", &self.value)?;
+        }
+        if let Some(ref hyperlink) = self.hyperlink {
+            state.serialize_field("hyperlink", hyperlink)?;
+        }
+        state.end()
+    }
 }
@@ -0,0 +1,46 @@
+use {ColumnId, RowId, SheetId};
+use dto::CellLinkStatus;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CellLink {
+    #[serde(skip_serializing)]
+    status: Option<CellLinkStatus>,
+    sheet_id: SheetId,
+    row_id: RowId,
+    column_id: ColumnId,
+    #[serde(skip_serializing)]
+    sheet_name: Option<String>,
+}
+
+impl CellLink {
+    pub fn new(sheet_id: SheetId, row_id: RowId, column_id: ColumnId) -> Self {
+        CellLink {
+            status: None,
+            sheet_id,
+            row_id,
+            column_id,
+            sheet_name: None,
+        }
+    }
+
+    pub fn get_status(&self) -> Option<&CellLinkStatus> {
+        self.status.as_ref()
+    }
+
+    pub fn get_sheet_id(&self) -> SheetId {
+        self.sheet_id.clone()
+    }
+
+    pub fn get_row_id(&self) -> RowId {
+        self.row_id.clone()
+    }
+
+    pub fn get_column_id(&self) -> ColumnId {
+        self.column_id.clone()
+    }
+
+    pub fn get_sheet_name(&self) -> Option<&str> {
+        self.sheet_name.as_ref().map(String::as_str)
+    }
+}
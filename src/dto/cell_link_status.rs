@@ -0,0 +1,38 @@
+use serde::{Deserialize, Deserializer};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CellLinkStatus {
+    Ok,
+    Broken,
+    Inaccessible,
+    NotShared,
+    Blocked,
+    Circular,
+    Invalid,
+    Disabled,
+    Other(String),
+}
+
+impl<'a> From<&'a str> for CellLinkStatus {
+    fn from(string: &str) -> CellLinkStatus {
+        match string {
+            "OK" => CellLinkStatus::Ok,
+            "BROKEN" => CellLinkStatus::Broken,
+            "INACCESSIBLE" => CellLinkStatus::Inaccessible,
+            "NOT_SHARED" => CellLinkStatus::NotShared,
+            "BLOCKED" => CellLinkStatus::Blocked,
+            "CIRCULAR" => CellLinkStatus::Circular,
+            "INVALID" => CellLinkStatus::Invalid,
+            "DISABLED" => CellLinkStatus::Disabled,
+            other => CellLinkStatus::Other(other.to_string()),
+        }
+    }
+}
+
+// Implemented manually to keep statuses unknown to this crate
+impl<'de> Deserialize<'de> for CellLinkStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        Ok(CellLinkStatus::from(string.as_str()))
+    }
+}
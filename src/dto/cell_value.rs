@@ -1,9 +1,20 @@
-#[derive(Debug, Deserialize, PartialEq, Serialize)]
-#[serde(untagged)]
+use dto::{ColumnType, Contact, DurationValue, Predecessor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::{Error as DeError, Visitor};
+use std::fmt::{Error as FmtError, Formatter};
+
+#[derive(Clone, Debug, PartialEq)]
 pub enum CellValue {
     Text(String),
     Number(f64),
     Bool(bool),
+    Date(String),
+    DateTime(String),
+    Contact(Contact),
+    MultiContact(Vec<Contact>),
+    MultiPicklist(Vec<String>),
+    Duration(DurationValue),
+    Predecessors(Vec<Predecessor>),
     Empty,
 }
 
@@ -14,6 +25,29 @@ impl CellValue {
             _ => None,
         }
     }
+
+    // These values can be written and read only as cell's object value
+    pub fn is_object_value(&self) -> bool {
+        match *self {
+            CellValue::MultiContact(_)
+            | CellValue::MultiPicklist(_)
+            | CellValue::Duration(_)
+            | CellValue::Predecessors(_) => true,
+            _ => false,
+        }
+    }
+
+    crate fn interpret(self, column_type: &ColumnType) -> CellValue {
+        match (column_type, self) {
+            (&ColumnType::Date, CellValue::Text(date)) => CellValue::Date(date),
+            (&ColumnType::DateTime, CellValue::Text(date_time))
+            | (&ColumnType::AbstractDateTime, CellValue::Text(date_time)) => CellValue::DateTime(date_time),
+            // Free text typed into a contact column stays a text
+            (&ColumnType::ContactList, CellValue::Text(ref contact)) if contact.contains('@') =>
+                CellValue::Contact(Contact::new(contact.clone())),
+            (_, cell_value) => cell_value,
+        }
+    }
 }
 
 impl Default for CellValue {
@@ -33,3 +67,121 @@ impl<'a> From<&'a str> for CellValue {
         CellValue::Text(string.to_string())
     }
 }
+
+impl From<f64> for CellValue {
+    fn from(number: f64) -> CellValue {
+        CellValue::Number(number)
+    }
+}
+
+impl From<i64> for CellValue {
+    fn from(number: i64) -> CellValue {
+        CellValue::Number(number as f64)
+    }
+}
+
+impl From<u64> for CellValue {
+    fn from(number: u64) -> CellValue {
+        CellValue::Number(number as f64)
+    }
+}
+
+impl From<bool> for CellValue {
+    fn from(boolean: bool) -> CellValue {
+        CellValue::Bool(boolean)
+    }
+}
+
+impl From<Contact> for CellValue {
+    fn from(contact: Contact) -> CellValue {
+        CellValue::Contact(contact)
+    }
+}
+
+impl From<DurationValue> for CellValue {
+    fn from(duration: DurationValue) -> CellValue {
+        CellValue::Duration(duration)
+    }
+}
+
+impl<T: Into<CellValue>> From<Option<T>> for CellValue {
+    fn from(value: Option<T>) -> CellValue {
+        value.map_or(CellValue::Empty, Into::into)
+    }
+}
+
+// Object values are serialized in the cell's objectValue field, see Cell
+impl Serialize for CellValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            CellValue::Text(ref text)
+            | CellValue::Date(ref text)
+            | CellValue::DateTime(ref text) => serializer.serialize_str(text),
+            CellValue::Number(number) => serializer.serialize_f64(number),
+            CellValue::Bool(boolean) => serializer.serialize_bool(boolean),
+            CellValue::Contact(ref contact) => {
+                let text = contact.get_email()
+                    .or_else(|| contact.get_name())
+                    .unwrap_or("");
+                serializer.serialize_str(text)
+            },
+            CellValue::MultiContact(_)
+            | CellValue::MultiPicklist(_)
+            | CellValue::Duration(_)
+            | CellValue::Predecessors(_) => serializer.serialize_unit(),
+            CellValue::Empty => serializer.serialize_unit(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CellValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(CellValueVisitor)
+    }
+}
+
+struct CellValueVisitor;
+
+impl<'de> Visitor<'de> for CellValueVisitor {
+    type Value = CellValue;
+
+    fn expecting(&self, formatter: &mut Formatter) -> Result<(), FmtError> {
+        formatter.write_str("a string, number, boolean or null")
+    }
+
+    fn visit_bool<E: DeError>(self, value: bool) -> Result<CellValue, E> {
+        Ok(CellValue::Bool(value))
+    }
+
+    fn visit_i64<E: DeError>(self, value: i64) -> Result<CellValue, E> {
+        Ok(CellValue::Number(value as f64))
+    }
+
+    fn visit_u64<E: DeError>(self, value: u64) -> Result<CellValue, E> {
+        Ok(CellValue::Number(value as f64))
+    }
+
+    fn visit_f64<E: DeError>(self, value: f64) -> Result<CellValue, E> {
+        Ok(CellValue::Number(value))
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<CellValue, E> {
+        Ok(CellValue::Text(value.to_string()))
+    }
+
+    fn visit_string<E: DeError>(self, value: String) -> Result<CellValue, E> {
+        Ok(CellValue::Text(value))
+    }
+
+    fn visit_unit<E: DeError>(self) -> Result<CellValue, E> {
+        Ok(CellValue::Empty)
+    }
+
+    fn visit_none<E: DeError>(self) -> Result<CellValue, E> {
+        Ok(CellValue::Empty)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<CellValue, D::Error> {
+        CellValue::deserialize(deserializer)
+    }
+}
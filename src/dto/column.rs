@@ -1,9 +1,29 @@
 use ColumnId;
+use dto::ColumnType;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct Column {
     id: ColumnId,
     title: String,
+    #[serde(default, rename = "type")]
+    column_type: ColumnType,
+    #[serde(default)]
+    index: usize,
+    #[serde(default)]
+    primary: bool,
+    #[serde(default)]
+    options: Vec<String>,
+    symbol: Option<String>,
+    system_column_type: Option<String>,
+    #[serde(default)]
+    validation: bool,
+    width: Option<u64>,
+    #[serde(default)]
+    hidden: bool,
+    #[serde(default)]
+    locked: bool,
+    format: Option<String>,
 }
 
 impl Column {
@@ -14,4 +34,56 @@ impl Column {
     pub fn get_title(&self) -> &str {
         &self.title
     }
+
+    pub fn get_type(&self) -> &ColumnType {
+        &self.column_type
+    }
+
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    pub fn is_primary(&self) -> bool {
+        self.primary
+    }
+
+    pub fn get_options(&self) -> &[String] {
+        &self.options
+    }
+
+    pub fn get_symbol(&self) -> Option<&str> {
+        self.symbol.as_ref().map(String::as_str)
+    }
+
+    pub fn get_system_column_type(&self) -> Option<&str> {
+        self.system_column_type.as_ref().map(String::as_str)
+    }
+
+    pub fn has_validation(&self) -> bool {
+        self.validation
+    }
+
+    pub fn get_width(&self) -> Option<u64> {
+        self.width
+    }
+
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    pub fn get_format(&self) -> Option<&str> {
+        self.format.as_ref().map(String::as_str)
+    }
+
+    pub fn accepts_option(&self, value: &str) -> bool {
+        let is_strict_picklist = self.validation && match self.column_type {
+            ColumnType::Picklist | ColumnType::MultiPicklist => true,
+            _ => false,
+        };
+        !is_strict_picklist || self.options.iter().any(|option| option == value)
+    }
 }
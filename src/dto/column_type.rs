@@ -0,0 +1,75 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColumnType {
+    TextNumber,
+    Date,
+    DateTime,
+    ContactList,
+    MultiContactList,
+    Checkbox,
+    Picklist,
+    MultiPicklist,
+    Duration,
+    Predecessor,
+    AbstractDateTime,
+    Other(String),
+}
+
+impl ColumnType {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ColumnType::TextNumber => "TEXT_NUMBER",
+            ColumnType::Date => "DATE",
+            ColumnType::DateTime => "DATETIME",
+            ColumnType::ContactList => "CONTACT_LIST",
+            ColumnType::MultiContactList => "MULTI_CONTACT_LIST",
+            ColumnType::Checkbox => "CHECKBOX",
+            ColumnType::Picklist => "PICKLIST",
+            ColumnType::MultiPicklist => "MULTI_PICKLIST",
+            ColumnType::Duration => "DURATION",
+            ColumnType::Predecessor => "PREDECESSOR",
+            ColumnType::AbstractDateTime => "ABSTRACT_DATETIME",
+            ColumnType::Other(ref other) => other,
+        }
+    }
+}
+
+impl Default for ColumnType {
+    fn default() -> ColumnType {
+        ColumnType::TextNumber
+    }
+}
+
+impl<'a> From<&'a str> for ColumnType {
+    fn from(string: &str) -> ColumnType {
+        match string {
+            "TEXT_NUMBER" => ColumnType::TextNumber,
+            "DATE" => ColumnType::Date,
+            "DATETIME" => ColumnType::DateTime,
+            "CONTACT_LIST" => ColumnType::ContactList,
+            "MULTI_CONTACT_LIST" => ColumnType::MultiContactList,
+            "CHECKBOX" => ColumnType::Checkbox,
+            "PICKLIST" => ColumnType::Picklist,
+            "MULTI_PICKLIST" => ColumnType::MultiPicklist,
+            "DURATION" => ColumnType::Duration,
+            "PREDECESSOR" => ColumnType::Predecessor,
+            "ABSTRACT_DATETIME" => ColumnType::AbstractDateTime,
+            other => ColumnType::Other(other.to_string()),
+        }
+    }
+}
+
+// Implemented manually to keep column types unknown to this crate
+impl<'de> Deserialize<'de> for ColumnType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        Ok(ColumnType::from(string.as_str()))
+    }
+}
+
+impl Serialize for ColumnType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
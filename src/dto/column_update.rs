@@ -0,0 +1,66 @@
+use dto::ColumnType;
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    column_type: Option<ColumnType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    validation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<u64>,
+}
+
+impl ColumnUpdate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_title<T: Into<String>>(self, title: T) -> Self {
+        ColumnUpdate {
+            title: Some(title.into()),
+            ..self
+        }
+    }
+
+    pub fn with_type(self, column_type: ColumnType) -> Self {
+        ColumnUpdate {
+            column_type: Some(column_type),
+            ..self
+        }
+    }
+
+    pub fn with_index(self, index: usize) -> Self {
+        ColumnUpdate {
+            index: Some(index),
+            ..self
+        }
+    }
+
+    pub fn with_options(self, options: Vec<String>) -> Self {
+        ColumnUpdate {
+            options: Some(options),
+            ..self
+        }
+    }
+
+    pub fn with_validation(self, validation: bool) -> Self {
+        ColumnUpdate {
+            validation: Some(validation),
+            ..self
+        }
+    }
+
+    pub fn with_width(self, width: u64) -> Self {
+        ColumnUpdate {
+            width: Some(width),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,36 @@
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Contact {
+    email: Option<String>,
+    name: Option<String>,
+}
+
+impl Contact {
+    pub fn new<T: Into<String>>(email: T) -> Self {
+        Contact {
+            email: Some(email.into()),
+            name: None,
+        }
+    }
+
+    pub fn with_name<T: Into<String>>(self, name: T) -> Self {
+        Contact {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+
+    crate fn new_with_parts(email: Option<String>, name: Option<String>) -> Self {
+        Contact {
+            email,
+            name,
+        }
+    }
+
+    pub fn get_email(&self) -> Option<&str> {
+        self.email.as_ref().map(String::as_str)
+    }
+
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.as_ref().map(String::as_str)
+    }
+}
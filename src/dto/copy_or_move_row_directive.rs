@@ -0,0 +1,25 @@
+use {RowId, SheetId};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyOrMoveRowDirective<'a> {
+    row_ids: &'a [RowId],
+    to: CopyOrMoveRowDestination,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CopyOrMoveRowDestination {
+    sheet_id: SheetId,
+}
+
+impl<'a> CopyOrMoveRowDirective<'a> {
+    pub fn new(row_ids: &'a [RowId], sheet_id: SheetId) -> Self {
+        CopyOrMoveRowDirective {
+            row_ids,
+            to: CopyOrMoveRowDestination {
+                sheet_id,
+            },
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use RowId;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyOrMoveRowResult {
+    #[serde(default)]
+    row_mappings: Vec<RowMapping>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RowMapping {
+    from: RowId,
+    to: RowId,
+}
+
+impl CopyOrMoveRowResult {
+    pub fn into_row_mappings(self) -> BTreeMap<RowId, RowId> {
+        self.row_mappings.into_iter()
+            .map(|mapping| (mapping.from, mapping.to))
+            .collect()
+    }
+}
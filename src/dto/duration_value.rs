@@ -0,0 +1,100 @@
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DurationValue {
+    #[serde(default)]
+    negative: bool,
+    #[serde(default)]
+    elapsed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weeks: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    days: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hours: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    minutes: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seconds: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    milliseconds: Option<f64>,
+}
+
+impl DurationValue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_negative(self, negative: bool) -> Self {
+        DurationValue {
+            negative,
+            ..self
+        }
+    }
+
+    pub fn with_elapsed(self, elapsed: bool) -> Self {
+        DurationValue {
+            elapsed,
+            ..self
+        }
+    }
+
+    pub fn with_weeks(self, weeks: f64) -> Self {
+        DurationValue {
+            weeks: Some(weeks),
+            ..self
+        }
+    }
+
+    pub fn with_days(self, days: f64) -> Self {
+        DurationValue {
+            days: Some(days),
+            ..self
+        }
+    }
+
+    pub fn with_hours(self, hours: f64) -> Self {
+        DurationValue {
+            hours: Some(hours),
+            ..self
+        }
+    }
+
+    pub fn with_minutes(self, minutes: f64) -> Self {
+        DurationValue {
+            minutes: Some(minutes),
+            ..self
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn is_elapsed(&self) -> bool {
+        self.elapsed
+    }
+
+    pub fn get_weeks(&self) -> Option<f64> {
+        self.weeks
+    }
+
+    pub fn get_days(&self) -> Option<f64> {
+        self.days
+    }
+
+    pub fn get_hours(&self) -> Option<f64> {
+        self.hours
+    }
+
+    pub fn get_minutes(&self) -> Option<f64> {
+        self.minutes
+    }
+
+    pub fn get_seconds(&self) -> Option<f64> {
+        self.seconds
+    }
+
+    pub fn get_milliseconds(&self) -> Option<f64> {
+        self.milliseconds
+    }
+}
@@ -1,8 +1,12 @@
+use serde_json::Value;
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Error {
     error_code: u64,
     message: String,
+    ref_id: Option<String>,
+    detail: Option<Value>,
 }
 
 impl Error {
@@ -10,6 +14,14 @@ impl Error {
         self.error_code
     }
 
+    pub fn get_ref_id(&self) -> Option<&str> {
+        self.ref_id.as_ref().map(String::as_str)
+    }
+
+    pub fn get_detail(&self) -> Option<&Value> {
+        self.detail.as_ref()
+    }
+
     pub fn into_message(self) -> String {
         self.message
     }
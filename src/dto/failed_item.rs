@@ -0,0 +1,24 @@
+use RowId;
+use dto::Error;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailedItem {
+    index: usize,
+    error: Error,
+    row_id: Option<RowId>,
+}
+
+impl FailedItem {
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    pub fn get_row_id(&self) -> Option<RowId> {
+        self.row_id.clone()
+    }
+
+    pub fn into_error(self) -> Error {
+        self.error
+    }
+}
@@ -0,0 +1,14 @@
+use dto::SheetHeader;
+
+// Workspaces are read as folders too, only the sheets directly inside are listed
+#[derive(Debug, Deserialize)]
+pub struct Folder {
+    #[serde(default)]
+    sheets: Vec<SheetHeader>,
+}
+
+impl Folder {
+    pub fn into_sheets(self) -> Vec<SheetHeader> {
+        self.sheets
+    }
+}
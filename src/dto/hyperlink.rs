@@ -0,0 +1,50 @@
+use SheetId;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Hyperlink {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sheet_id: Option<SheetId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    report_id: Option<u64>,
+}
+
+impl Hyperlink {
+    pub fn new_url<T: Into<String>>(url: T) -> Self {
+        Hyperlink {
+            url: Some(url.into()),
+            sheet_id: None,
+            report_id: None,
+        }
+    }
+
+    pub fn new_sheet(sheet_id: SheetId) -> Self {
+        Hyperlink {
+            url: None,
+            sheet_id: Some(sheet_id),
+            report_id: None,
+        }
+    }
+
+    pub fn new_report(report_id: u64) -> Self {
+        Hyperlink {
+            url: None,
+            sheet_id: None,
+            report_id: Some(report_id),
+        }
+    }
+
+    pub fn get_url(&self) -> Option<&str> {
+        self.url.as_ref().map(String::as_str)
+    }
+
+    pub fn get_sheet_id(&self) -> Option<SheetId> {
+        self.sheet_id.clone()
+    }
+
+    pub fn get_report_id(&self) -> Option<u64> {
+        self.report_id
+    }
+}
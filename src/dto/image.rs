@@ -0,0 +1,26 @@
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Image {
+    id: String,
+    height: Option<u64>,
+    width: Option<u64>,
+    alt_text: Option<String>,
+}
+
+impl Image {
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn get_height(&self) -> Option<u64> {
+        self.height
+    }
+
+    pub fn get_width(&self) -> Option<u64> {
+        self.width
+    }
+
+    pub fn get_alt_text(&self) -> Option<&str> {
+        self.alt_text.as_ref().map(String::as_str)
+    }
+}
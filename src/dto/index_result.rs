@@ -1,7 +1,12 @@
 use serde::de::DeserializeOwned;
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct IndexResult<T: DeserializeOwned> {
+    page_number: Option<u32>,
+    page_size: Option<u32>,
+    total_pages: Option<u32>,
+    total_count: Option<u64>,
     // Annotation required because of https://github.com/serde-rs/serde/issues/1296
     // Remove when https://github.com/rust-lang/rust/issues/41617 is fixed
     #[serde(bound = "")]
@@ -9,6 +14,26 @@ pub struct IndexResult<T: DeserializeOwned> {
 }
 
 impl<T: DeserializeOwned> IndexResult<T> {
+    pub fn get_page_number(&self) -> Option<u32> {
+        self.page_number
+    }
+
+    pub fn get_page_size(&self) -> Option<u32> {
+        self.page_size
+    }
+
+    pub fn get_total_pages(&self) -> Option<u32> {
+        self.total_pages
+    }
+
+    pub fn get_total_count(&self) -> Option<u64> {
+        self.total_count
+    }
+
+    pub fn get_data(&self) -> &[T] {
+        &self.data
+    }
+
     pub fn into_data(self) -> Vec<T> {
         self.data
     }
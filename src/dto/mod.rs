@@ -1,19 +1,65 @@
 mod api_result;
 mod cell;
+mod cell_link;
+mod cell_link_status;
 mod cell_value;
 mod column;
+mod column_type;
+mod column_update;
+mod contact;
+mod copy_or_move_row_directive;
+mod copy_or_move_row_result;
+mod duration_value;
 mod error;
+mod failed_item;
+mod folder;
+mod hyperlink;
+mod image;
 mod index_result;
+mod new_column;
+mod new_row;
+mod object_value;
+mod predecessor;
 mod row;
+mod row_list;
+mod row_location;
+mod row_move;
 mod sheet;
 mod sheet_header;
+mod sheet_version;
+mod sort_criterion;
+mod sort_direction;
+mod sort_specifier;
 
 pub use self::api_result::ApiResult;
 pub use self::cell::Cell;
+pub use self::cell_link::CellLink;
+pub use self::cell_link_status::CellLinkStatus;
 pub use self::cell_value::CellValue;
 pub use self::column::Column;
+pub use self::column_type::ColumnType;
+pub use self::column_update::ColumnUpdate;
+pub use self::contact::Contact;
+pub use self::copy_or_move_row_directive::CopyOrMoveRowDirective;
+pub use self::copy_or_move_row_result::CopyOrMoveRowResult;
+pub use self::duration_value::DurationValue;
 pub use self::error::Error;
+pub use self::failed_item::FailedItem;
+pub use self::folder::Folder;
+pub use self::hyperlink::Hyperlink;
+pub use self::image::Image;
 pub use self::index_result::IndexResult;
+pub use self::new_column::NewColumn;
+pub use self::new_row::NewRow;
+pub use self::object_value::ObjectValue;
+pub use self::predecessor::Predecessor;
 pub use self::row::Row;
+pub use self::row_list::RowList;
+pub use self::row_location::RowLocation;
+pub use self::row_move::RowMove;
 pub use self::sheet::Sheet;
 pub use self::sheet_header::SheetHeader;
+pub use self::sheet_version::SheetVersion;
+pub use self::sort_criterion::SortCriterion;
+pub use self::sort_direction::SortDirection;
+pub use self::sort_specifier::SortSpecifier;
@@ -0,0 +1,54 @@
+use dto::ColumnType;
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewColumn {
+    title: String,
+    #[serde(rename = "type")]
+    column_type: ColumnType,
+    index: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    options: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    validation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<u64>,
+}
+
+impl NewColumn {
+    pub fn new<T: Into<String>>(title: T, column_type: ColumnType) -> Self {
+        NewColumn {
+            title: title.into(),
+            column_type,
+            index: 0,
+            options: Vec::new(),
+            validation: None,
+            width: None,
+        }
+    }
+
+    pub fn with_options(self, options: Vec<String>) -> Self {
+        NewColumn {
+            options,
+            ..self
+        }
+    }
+
+    pub fn with_validation(self, validation: bool) -> Self {
+        NewColumn {
+            validation: Some(validation),
+            ..self
+        }
+    }
+
+    pub fn with_width(self, width: u64) -> Self {
+        NewColumn {
+            width: Some(width),
+            ..self
+        }
+    }
+
+    crate fn set_index(&mut self, index: usize) {
+        self.index = index;
+    }
+}
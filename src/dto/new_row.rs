@@ -0,0 +1,25 @@
+use dto::{Cell, RowLocation};
+
+#[derive(Debug, Serialize)]
+pub struct NewRow {
+    #[serde(flatten)]
+    location: RowLocation,
+    cells: Vec<Cell>,
+}
+
+impl NewRow {
+    pub fn new(location: RowLocation, cells: Vec<Cell>) -> Self {
+        NewRow {
+            location,
+            cells,
+        }
+    }
+
+    pub fn get_location(&self) -> &RowLocation {
+        &self.location
+    }
+
+    pub fn get_cells_iter(&self) -> impl Iterator<Item = &Cell> {
+        self.cells.iter()
+    }
+}
@@ -0,0 +1,74 @@
+use CellValue;
+use dto::{Contact, DurationValue, Predecessor};
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "objectType", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ObjectValue {
+    Contact {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        email: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+    },
+    MultiContact {
+        values: Vec<ObjectValue>,
+    },
+    MultiPicklist {
+        values: Vec<String>,
+    },
+    AbstractDatetime {
+        value: String,
+    },
+    Duration(DurationValue),
+    PredecessorList {
+        predecessors: Vec<Predecessor>,
+    },
+}
+
+impl ObjectValue {
+    pub fn from_cell_value(cell_value: &CellValue) -> Option<ObjectValue> {
+        let object_value = match *cell_value {
+            // Name is lost when a contact is written as a plain value
+            CellValue::Contact(ref contact) if contact.get_email().is_some() => ObjectValue::Contact {
+                email: contact.get_email().map(str::to_string),
+                name: contact.get_name().map(str::to_string),
+            },
+            CellValue::MultiContact(ref contacts) => ObjectValue::MultiContact {
+                values: contacts.iter()
+                    .map(|contact| ObjectValue::Contact {
+                        email: contact.get_email().map(str::to_string),
+                        name: contact.get_name().map(str::to_string),
+                    })
+                    .collect(),
+            },
+            CellValue::MultiPicklist(ref values) => ObjectValue::MultiPicklist {
+                values: values.clone(),
+            },
+            CellValue::Duration(ref duration) => ObjectValue::Duration(duration.clone()),
+            CellValue::Predecessors(ref predecessors) => ObjectValue::PredecessorList {
+                predecessors: predecessors.clone(),
+            },
+            _ => return None,
+        };
+        Some(object_value)
+    }
+
+    pub fn into_cell_value(self) -> CellValue {
+        match self {
+            ObjectValue::Contact { email, name } => CellValue::Contact(Contact::new_with_parts(email, name)),
+            ObjectValue::MultiContact { values } => {
+                let contacts = values.into_iter()
+                    .filter_map(|value| match value {
+                        ObjectValue::Contact { email, name } => Some(Contact::new_with_parts(email, name)),
+                        _ => None,
+                    })
+                    .collect();
+                CellValue::MultiContact(contacts)
+            },
+            ObjectValue::MultiPicklist { values } => CellValue::MultiPicklist(values),
+            ObjectValue::AbstractDatetime { value } => CellValue::DateTime(value),
+            ObjectValue::Duration(duration) => CellValue::Duration(duration),
+            ObjectValue::PredecessorList { predecessors } => CellValue::Predecessors(predecessors),
+        }
+    }
+}
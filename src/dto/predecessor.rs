@@ -0,0 +1,62 @@
+use RowId;
+use dto::DurationValue;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Predecessor {
+    row_id: RowId,
+    #[serde(skip_serializing)]
+    row_number: Option<u64>,
+    #[serde(rename = "type")]
+    predecessor_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lag: Option<DurationValue>,
+    #[serde(default, skip_serializing)]
+    invalid: bool,
+    #[serde(default, skip_serializing)]
+    in_critical_path: bool,
+}
+
+impl Predecessor {
+    pub fn new<T: Into<String>>(row_id: RowId, predecessor_type: T) -> Self {
+        Predecessor {
+            row_id,
+            row_number: None,
+            predecessor_type: predecessor_type.into(),
+            lag: None,
+            invalid: false,
+            in_critical_path: false,
+        }
+    }
+
+    pub fn with_lag(self, lag: DurationValue) -> Self {
+        Predecessor {
+            lag: Some(lag),
+            ..self
+        }
+    }
+
+    pub fn get_row_id(&self) -> RowId {
+        self.row_id.clone()
+    }
+
+    pub fn get_row_number(&self) -> Option<u64> {
+        self.row_number
+    }
+
+    pub fn get_type(&self) -> &str {
+        &self.predecessor_type
+    }
+
+    pub fn get_lag(&self) -> Option<&DurationValue> {
+        self.lag.as_ref()
+    }
+
+    pub fn is_invalid(&self) -> bool {
+        self.invalid
+    }
+
+    pub fn is_in_critical_path(&self) -> bool {
+        self.in_critical_path
+    }
+}
@@ -1,26 +1,77 @@
 use {CellValue, ColumnId, RowId};
-use dto::Cell;
+use dto::{Cell, Column, Contact};
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeStruct;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct Row {
     id: RowId,
+    #[serde(default)]
     cells: Vec<Cell>,
+    row_number: Option<u64>,
+    parent_id: Option<RowId>,
+    sibling_id: Option<RowId>,
+    expanded: Option<bool>,
+    #[serde(default)]
+    locked: bool,
+    #[serde(default)]
+    locked_for_user: bool,
+    created_at: Option<String>,
+    modified_at: Option<String>,
+    created_by: Option<Contact>,
+    modified_by: Option<Contact>,
+    version: Option<u64>,
+    format: Option<String>,
 }
 
 impl Row {
     pub fn new(id: RowId, cell: Cell) -> Self {
+        Self::new_with_cells(id, vec![cell])
+    }
+
+    pub fn new_with_cells(id: RowId, cells: Vec<Cell>) -> Self {
         Row {
             id,
-            cells: vec![cell],
+            cells,
+            row_number: None,
+            parent_id: None,
+            sibling_id: None,
+            expanded: None,
+            locked: false,
+            locked_for_user: false,
+            created_at: None,
+            modified_at: None,
+            created_by: None,
+            modified_by: None,
+            version: None,
+            format: None,
         }
     }
 
-    pub fn get_cell_value(&self, column_id: &ColumnId) -> Option<&CellValue> {
+    pub fn get_cell(&self, column_id: &ColumnId) -> Option<&Cell> {
         self.cells.iter()
             .find(|cell| &cell.get_column_id() == column_id)
+    }
+
+    pub fn get_cell_value(&self, column_id: &ColumnId) -> Option<&CellValue> {
+        self.get_cell(column_id)
             .map(|cell| cell.get_value())
     }
 
+    pub fn remove_cell(&mut self, column_id: &ColumnId) {
+        self.cells.retain(|cell| &cell.get_column_id() != column_id);
+    }
+
+    pub fn interpret_cells(&mut self, columns: &[Column]) {
+        for cell in &mut self.cells {
+            let column_id = cell.get_column_id();
+            if let Some(column) = columns.iter().find(|column| column.get_column_id() == column_id) {
+                cell.interpret(column.get_type());
+            }
+        }
+    }
+
     pub fn get_row_id(&self) -> RowId {
         self.id.clone()
     }
@@ -28,4 +79,67 @@ impl Row {
     pub fn get_cells_iter(&self) -> impl Iterator<Item = &Cell> {
         self.cells.iter()
     }
+
+    pub fn get_row_number(&self) -> Option<u64> {
+        self.row_number
+    }
+
+    crate fn set_row_number(&mut self, row_number: u64) {
+        self.row_number = Some(row_number);
+    }
+
+    pub fn get_parent_id(&self) -> Option<RowId> {
+        self.parent_id.clone()
+    }
+
+    pub fn get_sibling_id(&self) -> Option<RowId> {
+        self.sibling_id.clone()
+    }
+
+    pub fn is_expanded(&self) -> Option<bool> {
+        self.expanded
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    // Row is locked and the current user has no permission to edit it
+    pub fn is_locked_for_user(&self) -> bool {
+        self.locked_for_user
+    }
+
+    pub fn get_created_at(&self) -> Option<&str> {
+        self.created_at.as_ref().map(String::as_str)
+    }
+
+    pub fn get_modified_at(&self) -> Option<&str> {
+        self.modified_at.as_ref().map(String::as_str)
+    }
+
+    pub fn get_created_by(&self) -> Option<&Contact> {
+        self.created_by.as_ref()
+    }
+
+    pub fn get_modified_by(&self) -> Option<&Contact> {
+        self.modified_by.as_ref()
+    }
+
+    pub fn get_version(&self) -> Option<u64> {
+        self.version
+    }
+
+    pub fn get_format(&self) -> Option<&str> {
+        self.format.as_ref().map(String::as_str)
+    }
+}
+
+// Only the id and cells are written, metadata is maintained by Smartsheet
+impl Serialize for Row {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Row", 2)?;
+        state.serialize_field("id", &self.id)?;
+        state.serialize_field("cells", &self.cells)?;
+        state.end()
+    }
 }
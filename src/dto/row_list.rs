@@ -0,0 +1,181 @@
+use RowId;
+use dto::Row;
+use serde::{Deserialize, Deserializer};
+use std::cmp;
+use std::collections::{BTreeMap, BTreeSet};
+
+// Rows in sheet order, indexed by id
+#[derive(Debug)]
+pub struct RowList {
+    rows: Vec<Row>,
+    indexes: BTreeMap<RowId, usize>,
+    is_complete: bool,
+}
+
+impl RowList {
+    pub fn new(rows: Vec<Row>) -> Self {
+        let mut row_list = RowList {
+            rows,
+            indexes: BTreeMap::new(),
+            is_complete: true,
+        };
+        row_list.reindex();
+        row_list
+    }
+
+    // Row numbers of a partially loaded sheet can't be derived from positions
+    pub fn set_partial(&mut self) {
+        self.is_complete = false;
+    }
+
+    pub fn get(&self, row_id: &RowId) -> Option<&Row> {
+        self.indexes.get(row_id)
+            .map(|&index| &self.rows[index])
+    }
+
+    pub fn contains(&self, row_id: &RowId) -> bool {
+        self.indexes.contains_key(row_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Row> {
+        self.rows.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Row> {
+        self.rows.iter_mut()
+    }
+
+    pub fn into_vec(self) -> Vec<Row> {
+        self.rows
+    }
+
+    // Row numbers are updated once for all rows
+    pub fn upsert_all(&mut self, rows: impl IntoIterator<Item = Row>) {
+        let mut is_reordered = false;
+        for row in rows {
+            is_reordered |= self.upsert(row);
+        }
+        if is_reordered {
+            self.renumber();
+        }
+    }
+
+    // Moved rows are placed by their parent and sibling, their children are moved along
+    fn upsert(&mut self, row: Row) -> bool {
+        let existing = self.indexes.get(&row.get_row_id()).cloned();
+        let index = match existing {
+            Some(index) => index,
+            None => {
+                let position = self.find_position(&row);
+                self.rows.insert(position, row);
+                self.reindex_from(position);
+                return true
+            },
+        };
+        let is_moved = {
+            let current = &self.rows[index];
+            current.get_parent_id() != row.get_parent_id() || current.get_sibling_id() != row.get_sibling_id()
+        };
+        if !is_moved {
+            self.rows[index] = row;
+            return false
+        }
+        let end = self.find_subtree_end(index);
+        let mut moved: Vec<_> = self.rows.drain(index..end).collect();
+        for moved_row in &moved {
+            self.indexes.remove(&moved_row.get_row_id());
+        }
+        self.reindex_from(index);
+        moved[0] = row;
+        let position = self.find_position(&moved[0]);
+        let tail = self.rows.split_off(position);
+        self.rows.extend(moved);
+        self.rows.extend(tail);
+        self.reindex_from(position);
+        true
+    }
+
+    // Children of removed rows are removed as well
+    pub fn remove<'a>(&mut self, row_ids: impl IntoIterator<Item = &'a RowId>) {
+        let mut removed: BTreeSet<_> = row_ids.into_iter()
+            .cloned()
+            .collect();
+        for row in &self.rows {
+            if row.get_parent_id().map_or(false, |parent_id| removed.contains(&parent_id)) {
+                removed.insert(row.get_row_id());
+            }
+        }
+        self.rows.retain(|row| !removed.contains(&row.get_row_id()));
+        self.reindex();
+        self.renumber();
+    }
+
+    fn find_position(&self, row: &Row) -> usize {
+        if let Some(&index) = row.get_sibling_id().and_then(|sibling_id| self.indexes.get(&sibling_id)) {
+            return self.find_subtree_end(index)
+        }
+        if let Some(&index) = row.get_parent_id().and_then(|parent_id| self.indexes.get(&parent_id)) {
+            return index + 1
+        }
+        match row.get_row_number() {
+            Some(row_number) => cmp::min(row_number.saturating_sub(1) as usize, self.rows.len()),
+            None => self.rows.len(),
+        }
+    }
+
+    // Index just past the last descendant of the row at the given index
+    fn find_subtree_end(&self, index: usize) -> usize {
+        let row_id = self.rows[index].get_row_id();
+        let mut end = index + 1;
+        while end < self.rows.len() && self.is_descendant(&self.rows[end], &row_id) {
+            end += 1;
+        }
+        end
+    }
+
+    fn is_descendant(&self, row: &Row, ancestor_id: &RowId) -> bool {
+        let mut parent_id = row.get_parent_id();
+        while let Some(id) = parent_id {
+            if &id == ancestor_id {
+                return true
+            }
+            parent_id = self.get(&id).and_then(Row::get_parent_id);
+        }
+        false
+    }
+
+    fn reindex(&mut self) {
+        self.indexes = self.rows.iter()
+            .enumerate()
+            .map(|(index, row)| (row.get_row_id(), index))
+            .collect();
+    }
+
+    // Rows before the start keep their positions
+    fn reindex_from(&mut self, start: usize) {
+        for (index, row) in self.rows.iter().enumerate().skip(start) {
+            self.indexes.insert(row.get_row_id(), index);
+        }
+    }
+
+    // Row numbers follow the positions, as Smartsheet does
+    fn renumber(&mut self) {
+        if !self.is_complete {
+            return
+        }
+        for (index, row) in self.rows.iter_mut().enumerate() {
+            row.set_row_number(index as u64 + 1);
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RowList {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows = Vec::<Row>::deserialize(deserializer)?;
+        Ok(RowList::new(rows))
+    }
+}
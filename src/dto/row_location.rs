@@ -0,0 +1,48 @@
+use RowId;
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeMap;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RowLocation {
+    ToTop,
+    ToBottom,
+    FirstChildOf(RowId),
+    LastChildOf(RowId),
+    Above(RowId),
+    Below(RowId),
+}
+
+impl RowLocation {
+    // Row the location is relative to
+    crate fn get_anchor_id(&self) -> Option<&RowId> {
+        match *self {
+            RowLocation::ToTop | RowLocation::ToBottom => None,
+            RowLocation::FirstChildOf(ref row_id)
+            | RowLocation::LastChildOf(ref row_id)
+            | RowLocation::Above(ref row_id)
+            | RowLocation::Below(ref row_id) => Some(row_id),
+        }
+    }
+}
+
+// Serialized as location specifier fields of a row
+impl Serialize for RowLocation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        match *self {
+            RowLocation::ToTop => map.serialize_entry("toTop", &true)?,
+            RowLocation::ToBottom => map.serialize_entry("toBottom", &true)?,
+            RowLocation::FirstChildOf(ref parent_id) => map.serialize_entry("parentId", parent_id)?,
+            RowLocation::LastChildOf(ref parent_id) => {
+                map.serialize_entry("parentId", parent_id)?;
+                map.serialize_entry("toBottom", &true)?;
+            },
+            RowLocation::Above(ref sibling_id) => {
+                map.serialize_entry("siblingId", sibling_id)?;
+                map.serialize_entry("above", &true)?;
+            },
+            RowLocation::Below(ref sibling_id) => map.serialize_entry("siblingId", sibling_id)?,
+        }
+        map.end()
+    }
+}
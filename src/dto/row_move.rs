@@ -0,0 +1,18 @@
+use RowId;
+use dto::RowLocation;
+
+#[derive(Debug, Serialize)]
+pub struct RowMove {
+    id: RowId,
+    #[serde(flatten)]
+    location: RowLocation,
+}
+
+impl RowMove {
+    pub fn new(id: RowId, location: RowLocation) -> Self {
+        RowMove {
+            id,
+            location,
+        }
+    }
+}
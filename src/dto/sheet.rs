@@ -1,15 +1,17 @@
 use {CellValue, ColumnId, Row, RowId, SheetId};
-use dto::{Column, SheetHeader};
-use serde::{Deserialize, Deserializer};
+use dto::{Cell, Column, RowList, SheetHeader};
 use std::collections::BTreeMap;
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Sheet {
     #[serde(flatten)]
     header: SheetHeader,
+    version: Option<u64>,
+    modified_at: Option<String>,
+    total_row_count: Option<usize>,
     columns: Vec<Column>,
-    #[serde(deserialize_with = "deserialize_rows")]
-    rows: BTreeMap<RowId, Row>,
+    rows: RowList,
 }
 
 impl Sheet {
@@ -18,21 +20,153 @@ impl Sheet {
     }
 
     pub fn get_column_id(&self, title: &str) -> Option<ColumnId> {
+        self.get_column_by_title(title)
+            .map(|column| column.get_column_id())
+    }
+
+    pub fn get_column(&self, column_id: &ColumnId) -> Option<&Column> {
+        self.columns.iter()
+            .find(|column| &column.get_column_id() == column_id)
+    }
+
+    pub fn get_column_by_title(&self, title: &str) -> Option<&Column> {
         self.columns.iter()
             .find(|column| column.get_title() == title)
-            .map(|column| column.get_column_id())
+    }
+
+    pub fn get_columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    pub fn get_version(&self) -> Option<u64> {
+        self.version
+    }
+
+    pub fn get_modified_at(&self) -> Option<&str> {
+        self.modified_at.as_ref().map(String::as_str)
+    }
+
+    // Only our own write may be skipped, other changes must still be picked up by refresh
+    pub fn advance_version(&mut self, version: Option<u64>) {
+        if let (Some(current), Some(version)) = (self.version, version) {
+            if version == current + 1 {
+                self.version = Some(version);
+            }
+        }
+    }
+
+    // Number of rows in the whole sheet, even if it was loaded partially
+    pub fn get_total_row_count(&self) -> Option<usize> {
+        self.total_row_count
+    }
+
+    pub fn get_row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    // Takes over the state of the changed sheet, rows missing in it are kept
+    pub fn merge(&mut self, changed: Sheet) {
+        self.version = changed.version;
+        self.modified_at = changed.modified_at;
+        self.total_row_count = changed.total_row_count;
+        self.update_rows(changed.rows.into_vec());
+    }
+
+    pub fn set_partial(&mut self) {
+        self.rows.set_partial();
+    }
+
+    pub fn set_columns(&mut self, columns: Vec<Column>) {
+        self.columns = columns;
+    }
+
+    pub fn remove_column_cells(&mut self, column_id: &ColumnId) {
+        for row in self.rows.iter_mut() {
+            row.remove_cell(column_id);
+        }
     }
 
     pub fn find_row_id(&self, mut predicate: impl FnMut(&Row) -> bool) -> Option<RowId> {
-        self.rows.values()
+        self.rows.iter()
             .find(|row| predicate(*row))
             .map(|row| row.get_row_id())
     }
 
     pub fn update_rows(&mut self, rows: impl IntoIterator<Item = Row>) {
-        let extended = rows.into_iter()
-            .map(|row| (row.get_row_id(), row));
-        self.rows.extend(extended);
+        let columns = &self.columns;
+        let rows = rows.into_iter()
+            .map(|mut row| {
+                row.interpret_cells(columns);
+                row
+            });
+        self.rows.upsert_all(rows);
+    }
+
+    pub fn interpret_cells(&mut self) {
+        let columns = &self.columns;
+        for row in self.rows.iter_mut() {
+            row.interpret_cells(columns);
+        }
+    }
+
+    pub fn remove_rows<'a>(&mut self, row_ids: impl IntoIterator<Item = &'a RowId>) {
+        self.rows.remove(row_ids);
+    }
+
+    pub fn get_row(&self, row_id: &RowId) -> Option<&Row> {
+        self.rows.get(row_id)
+    }
+
+    pub fn get_children(&self, row_id: &RowId) -> Vec<&Row> {
+        self.rows.iter()
+            .filter(|row| row.get_parent_id().as_ref() == Some(row_id))
+            .collect()
+    }
+
+    // Nearest parent first
+    pub fn get_ancestors(&self, row_id: &RowId) -> Vec<&Row> {
+        let mut ancestors = Vec::new();
+        let mut parent_id = self.rows.get(row_id).and_then(Row::get_parent_id);
+        while let Some(id) = parent_id {
+            match self.rows.get(&id) {
+                Some(parent) => {
+                    ancestors.push(parent);
+                    parent_id = parent.get_parent_id();
+                },
+                None => break,
+            }
+        }
+        ancestors
+    }
+
+    pub fn get_depth(&self, row_id: &RowId) -> Option<usize> {
+        self.rows.get(row_id)?;
+        Some(self.get_ancestors(row_id).len())
+    }
+
+    // Rows with depth, parents before children, siblings in sheet order
+    pub fn walk_depth_first(&self) -> Vec<(usize, &Row)> {
+        let children = self.get_children_by_parent();
+        let mut walked = Vec::with_capacity(self.rows.len());
+        walk_children(&children, None, 0, &mut walked);
+        walked
+    }
+
+    // The row itself followed by all its descendants
+    pub fn get_subtree(&self, row_id: &RowId) -> Vec<(usize, &Row)> {
+        let row = match self.rows.get(row_id) {
+            Some(row) => row,
+            None => return Vec::new(),
+        };
+        let children = self.get_children_by_parent();
+        let mut walked = vec![(0, row)];
+        walk_children(&children, Some(row_id.clone()), 1, &mut walked);
+        walked
+    }
+
+    pub fn get_cell(&self, column_id: &ColumnId, row_id: &RowId) -> Option<&Cell> {
+        self.rows.get(row_id)?
+            .get_cell(column_id)
     }
 
     pub fn get_cell_value(&self, column_id: &ColumnId, row_id: &RowId) -> Option<&CellValue> {
@@ -41,14 +175,35 @@ impl Sheet {
     }
 
     pub fn get_rows_iter(&self) -> impl Iterator<Item = &Row> {
-        self.rows.values()
+        self.rows.iter()
+    }
+
+    pub fn into_rows(self) -> Vec<Row> {
+        self.rows.into_vec()
+    }
+
+    // Rows with parent missing in the sheet are treated as top level rows
+    fn get_children_by_parent(&self) -> BTreeMap<Option<RowId>, Vec<&Row>> {
+        let mut children = BTreeMap::new();
+        for row in self.rows.iter() {
+            let parent_id = row.get_parent_id()
+                .filter(|parent_id| self.rows.contains(parent_id));
+            children.entry(parent_id)
+                .or_insert_with(Vec::new)
+                .push(row);
+        }
+        children
     }
 }
 
-fn deserialize_rows<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BTreeMap<RowId, Row>, D::Error> {
-    let rows = Vec::<Row>::deserialize(deserializer)?
-        .into_iter()
-        .map(|row| (row.get_row_id(), row))
-        .collect();
-    Ok(rows)
+fn walk_children<'a>(children: &BTreeMap<Option<RowId>, Vec<&'a Row>>, parent_id: Option<RowId>, depth: usize,
+        walked: &mut Vec<(usize, &'a Row)>) {
+    let rows = match children.get(&parent_id) {
+        Some(rows) => rows,
+        None => return,
+    };
+    for row in rows {
+        walked.push((depth, *row));
+        walk_children(children, Some(row.get_row_id()), depth + 1, walked);
+    }
 }
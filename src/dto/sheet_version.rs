@@ -0,0 +1,10 @@
+#[derive(Debug, Deserialize)]
+pub struct SheetVersion {
+    version: u64,
+}
+
+impl SheetVersion {
+    pub fn get_version(&self) -> u64 {
+        self.version
+    }
+}
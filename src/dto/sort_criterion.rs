@@ -0,0 +1,26 @@
+use ColumnId;
+use dto::SortDirection;
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SortCriterion {
+    column_id: ColumnId,
+    direction: SortDirection,
+}
+
+impl SortCriterion {
+    pub fn new(column_id: ColumnId, direction: SortDirection) -> Self {
+        SortCriterion {
+            column_id,
+            direction,
+        }
+    }
+
+    pub fn ascending(column_id: ColumnId) -> Self {
+        Self::new(column_id, SortDirection::Ascending)
+    }
+
+    pub fn descending(column_id: ColumnId) -> Self {
+        Self::new(column_id, SortDirection::Descending)
+    }
+}
@@ -0,0 +1,15 @@
+use dto::SortCriterion;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SortSpecifier<'a> {
+    sort_criteria: &'a [SortCriterion],
+}
+
+impl<'a> SortSpecifier<'a> {
+    pub fn new(sort_criteria: &'a [SortCriterion]) -> Self {
+        SortSpecifier {
+            sort_criteria,
+        }
+    }
+}
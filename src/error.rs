@@ -1,16 +1,147 @@
+use {CellValue, ColumnId, Row, RowId};
 use dto::Error as DtoError;
 use reqwest::Error as ReqwestError;
+use serde_json::Value;
 use serde_json::error::Error as SerdeJsonError;
+use std::error::Error as StdError;
+use std::fmt::{Display, Error as FmtError, Formatter};
 
-#[derive(Debug, PartialEq)]
+const NOT_FOUND_CODES: &[u64] = &[1006, 1020];
+const UNAUTHORIZED_CODES: &[u64] = &[1001, 1002, 1003, 1004, 1005];
+const RATE_LIMITED_CODES: &[u64] = &[4003];
+const VALIDATION_CODES: &[u64] = &[1008, 1009, 1012, 1018, 1024, 1036, 1041, 1042];
+const SERVER_ERROR_CODES: &[u64] = &[4000, 4001, 4002, 4004];
+
+#[derive(Clone, Debug, PartialEq)]
 pub enum Error {
     Network(String),
     InvalidJson(String),
     InvalidSheetName(String),
-    SmartsheetOther { code: u64, message: String },
+    AmbiguousSheetName(String),
+    NotFound(ApiError),
+    Unauthorized(ApiError),
+    RateLimited(ApiError),
+    Validation(ApiError),
+    ServerError(ApiError),
+    SmartsheetOther(ApiError),
+    FormulaOverwrite { column_id: ColumnId, row_id: RowId },
+    RowMapping { row_id: Option<RowId>, error: MappingError },
+    Conflict { version: u64, rows: Vec<Row> },
+    RowNotDeleted(RowId),
     Other(String),
 }
 
+impl Error {
+    crate fn from_response(status: u16, error: DtoError) -> Error {
+        Self::from_api_error(ApiError::from_dto(status, error))
+    }
+
+    // Failed items of bulk operations are reported inside of a successful response
+    crate fn from_failed_item(error: DtoError) -> Error {
+        Self::from_api_error(ApiError {
+            status: None,
+            ..ApiError::from_dto(0, error)
+        })
+    }
+
+    // For error responses without Smartsheet error in the body
+    crate fn from_status<T: Into<String>>(status: u16, message: T) -> Error {
+        Self::from_api_error(ApiError {
+            status: Some(status),
+            code: None,
+            message: message.into(),
+            ref_id: None,
+            detail: None,
+        })
+    }
+
+    fn from_api_error(api_error: ApiError) -> Error {
+        let status = api_error.status.unwrap_or_default();
+        let code = api_error.code.unwrap_or_default();
+        if NOT_FOUND_CODES.contains(&code) || (status == 404 && !is_known_code(code)) {
+            Error::NotFound(api_error)
+        } else if UNAUTHORIZED_CODES.contains(&code) || ((status == 401 || status == 403) && !is_known_code(code)) {
+            Error::Unauthorized(api_error)
+        } else if RATE_LIMITED_CODES.contains(&code) || (status == 429 && !is_known_code(code)) {
+            Error::RateLimited(api_error)
+        } else if VALIDATION_CODES.contains(&code) || (status == 400 && !is_known_code(code)) {
+            Error::Validation(api_error)
+        } else if SERVER_ERROR_CODES.contains(&code) || (status >= 500 && !is_known_code(code)) {
+            Error::ServerError(api_error)
+        } else {
+            Error::SmartsheetOther(api_error)
+        }
+    }
+
+    pub fn get_api_error(&self) -> Option<&ApiError> {
+        match *self {
+            Error::NotFound(ref error)
+            | Error::Unauthorized(ref error)
+            | Error::RateLimited(ref error)
+            | Error::Validation(ref error)
+            | Error::ServerError(ref error)
+            | Error::SmartsheetOther(ref error) => Some(error),
+            _ => None,
+        }
+    }
+
+    fn get_kind(&self) -> &'static str {
+        match *self {
+            Error::Network(_) => "network error",
+            Error::InvalidJson(_) => "invalid JSON",
+            Error::InvalidSheetName(_) => "sheet not found",
+            Error::AmbiguousSheetName(_) => "ambiguous sheet name",
+            Error::NotFound(_) => "not found",
+            Error::Unauthorized(_) => "unauthorized",
+            Error::RateLimited(_) => "rate limited",
+            Error::Validation(_) => "validation failed",
+            Error::ServerError(_) => "server error",
+            Error::SmartsheetOther(_) => "Smartsheet error",
+            Error::FormulaOverwrite { .. } => "formula overwrite",
+            Error::RowMapping { .. } => "row mapping failed",
+            Error::Conflict { .. } => "edit conflict",
+            Error::RowNotDeleted(_) => "row not deleted",
+            Error::Other(_) => "other error",
+        }
+    }
+}
+
+fn is_known_code(code: u64) -> bool {
+    [NOT_FOUND_CODES, UNAUTHORIZED_CODES, RATE_LIMITED_CODES, VALIDATION_CODES, SERVER_ERROR_CODES].iter()
+        .any(|codes| codes.contains(&code))
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        match *self {
+            Error::Network(ref message) => write!(f, "network error: {}", message),
+            Error::InvalidJson(ref message) => write!(f, "invalid JSON: {}", message),
+            Error::InvalidSheetName(ref name) => write!(f, "sheet '{}' not found", name),
+            Error::AmbiguousSheetName(ref name) => write!(f, "more than one sheet named '{}'", name),
+            Error::FormulaOverwrite { ref column_id, ref row_id } =>
+                write!(f, "writing a value would overwrite formula in column {} and row {}", column_id, row_id),
+            Error::RowMapping { row_id: Some(ref row_id), ref error } => write!(f, "cannot map row {}: {}", row_id, error),
+            Error::RowMapping { row_id: None, ref error } => write!(f, "cannot map row: {}", error),
+            Error::Conflict { version, ref rows } =>
+                write!(f, "sheet changed to version {}, {} of the written rows were modified", version, rows.len()),
+            Error::RowNotDeleted(ref row_id) => write!(f, "row {} was not deleted", row_id),
+            Error::Other(ref message) => write!(f, "{}", message),
+            Error::NotFound(ref api_error)
+            | Error::Unauthorized(ref api_error)
+            | Error::RateLimited(ref api_error)
+            | Error::Validation(ref api_error)
+            | Error::ServerError(ref api_error)
+            | Error::SmartsheetOther(ref api_error) => write!(f, "{}: {}", self.get_kind(), api_error),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn description(&self) -> &str {
+        self.get_kind()
+    }
+}
+
 impl From<ReqwestError> for Error {
     fn from(error: ReqwestError) -> Error {
         Error::Network(error.to_string())
@@ -29,11 +160,193 @@ impl<'a> From<&'a str> for Error {
     }
 }
 
-impl From<DtoError> for Error {
-    fn from(error: DtoError) -> Error {
-        Error::SmartsheetOther {
-            code: error.get_code(),
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApiError {
+    status: Option<u16>,
+    code: Option<u64>,
+    message: String,
+    ref_id: Option<String>,
+    detail: Option<Value>,
+}
+
+impl ApiError {
+    fn from_dto(status: u16, error: DtoError) -> Self {
+        ApiError {
+            status: Some(status),
+            code: Some(error.get_code()),
+            ref_id: error.get_ref_id().map(str::to_string),
+            detail: error.get_detail().cloned(),
             message: error.into_message(),
         }
     }
+
+    pub fn get_status(&self) -> Option<u16> {
+        self.status
+    }
+
+    pub fn get_code(&self) -> Option<u64> {
+        self.code
+    }
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn get_ref_id(&self) -> Option<&str> {
+        self.ref_id.as_ref().map(String::as_str)
+    }
+
+    pub fn get_detail(&self) -> Option<&Value> {
+        self.detail.as_ref()
+    }
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        let mut details = Vec::new();
+        if let Some(code) = self.code {
+            details.push(format!("error code {}", code));
+        }
+        if let Some(status) = self.status {
+            details.push(format!("HTTP status {}", status));
+        }
+        if let Some(ref ref_id) = self.ref_id {
+            details.push(format!("ref ID {}", ref_id));
+        }
+        write!(f, "{} ({})", self.message, details.join(", "))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MappingError {
+    UnknownColumn(String),
+    MissingValue(String),
+    InvalidValue { column: String, expected: &'static str, actual: CellValue },
+}
+
+impl Display for MappingError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        match *self {
+            MappingError::UnknownColumn(ref column) => write!(f, "column '{}' not found", column),
+            MappingError::MissingValue(ref column) => write!(f, "missing value in column '{}'", column),
+            MappingError::InvalidValue { ref column, expected, ref actual } =>
+                write!(f, "expected {} in column '{}', found {:?}", expected, column, actual),
+        }
+    }
+}
+
+#[cfg(test)]
+impl ApiError {
+    crate fn new<T: Into<String>>(status: u16, code: u64, message: T) -> Self {
+        ApiError {
+            status: Some(status),
+            code: Some(code),
+            message: message.into(),
+            ref_id: None,
+            detail: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_dto_error(code: u64) -> DtoError {
+        serde_json::from_value(json!({
+                "errorCode": code,
+                "message": "Test error",
+                "refId": "abc123",
+                "detail": {
+                    "index": 1
+                }
+            })).unwrap()
+    }
+
+    mod from_response {
+        use super::*;
+
+        #[test]
+        fn maps_error_codes() {
+            let cases: &[(u16, u64, fn(ApiError) -> Error)] = &[
+                (404, 1006, Error::NotFound),
+                (403, 1004, Error::Unauthorized),
+                (429, 4003, Error::RateLimited),
+                (400, 1036, Error::Validation),
+                (503, 4001, Error::ServerError),
+                (400, 9999, Error::Validation),
+                (409, 9999, Error::SmartsheetOther),
+            ];
+
+            for &(status, code, variant) in cases {
+                let actual = Error::from_response(status, create_dto_error(code));
+
+                let expected = variant(ApiError::from_dto(status, create_dto_error(code)));
+                assert_eq!(expected, actual, "for status {} and code {}", status, code);
+            }
+        }
+
+        #[test]
+        fn maps_status_without_error_code() {
+            let cases: &[(u16, fn(ApiError) -> Error)] = &[
+                (404, Error::NotFound),
+                (401, Error::Unauthorized),
+                (429, Error::RateLimited),
+                (400, Error::Validation),
+                (503, Error::ServerError),
+                (409, Error::SmartsheetOther),
+            ];
+
+            for &(status, variant) in cases {
+                let actual = Error::from_status(status, "Test error");
+
+                let mut api_error = ApiError::new(status, 0, "Test error");
+                api_error.code = None;
+                assert_eq!(variant(api_error), actual, "for status {}", status);
+            }
+        }
+
+        #[test]
+        fn keeps_error_body() {
+            let actual = Error::from_response(404, create_dto_error(1006));
+
+            let api_error = actual.get_api_error().unwrap();
+            assert_eq!(Some(404), api_error.get_status());
+            assert_eq!(Some(1006), api_error.get_code());
+            assert_eq!("Test error", api_error.get_message());
+            assert_eq!(Some("abc123"), api_error.get_ref_id());
+            assert_eq!(Some(&json!({ "index": 1 })), api_error.get_detail());
+        }
+    }
+
+    mod display {
+        use super::*;
+
+        #[test]
+        fn describes_api_error() {
+            let error = Error::from_response(404, create_dto_error(1006));
+
+            let actual = error.to_string();
+
+            assert_eq!("not found: Test error (error code 1006, HTTP status 404, ref ID abc123)", actual);
+        }
+
+        #[test]
+        fn describes_failed_item_without_status() {
+            let error = Error::from_failed_item(create_dto_error(1036));
+
+            let actual = error.to_string();
+
+            assert_eq!("validation failed: Test error (error code 1036, ref ID abc123)", actual);
+        }
+
+        #[test]
+        fn describes_api_error_without_error_code() {
+            let error = Error::from_status(503, "Service Unavailable");
+
+            let actual = error.to_string();
+
+            assert_eq!("server error: Service Unavailable (HTTP status 503)", actual);
+        }
+    }
 }
@@ -0,0 +1,19 @@
+use std::fmt::{Display, Error as FmtError, Formatter};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct FolderId {
+    id: u64,
+}
+
+impl From<u64> for FolderId {
+    fn from(id: u64) -> Self {
+        FolderId { id }
+    }
+}
+
+impl Display for FolderId {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        self.id.fmt(f)
+    }
+}
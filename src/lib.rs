@@ -1,5 +1,7 @@
 #![feature(crate_visibility_modifier)]
 
+#[cfg(feature = "async")]
+extern crate futures;
 #[cfg(test)]
 extern crate mockito;
 extern crate reqwest;
@@ -8,21 +10,44 @@ extern crate serde;
 extern crate serde_derive;
 #[cfg_attr(test, macro_use)]
 extern crate serde_json;
+#[cfg(all(test, feature = "async"))]
+extern crate tokio;
+#[cfg(feature = "async")]
+extern crate tokio_timer;
 
+#[cfg(feature = "async")]
+pub mod async_client;
+mod bulk_result;
 mod client;
 mod column_id;
 mod dto;
 mod error;
+mod folder_id;
+mod pages;
+mod retry_policy;
 mod row_id;
+mod row_mapping;
 mod sheet_id;
+mod sheet_options;
 mod smartsheet;
+mod transfer_options;
+mod workspace_id;
 
+pub use self::bulk_result::{BulkFailure, BulkResult};
 pub use self::client::Client;
-pub use self::dto::{Cell, CellValue, Row, SheetHeader};
+pub use self::dto::{Cell, CellLink, CellLinkStatus, CellValue, Column, ColumnType, ColumnUpdate, Contact, DurationValue, Hyperlink, Image,
+                    IndexResult, NewColumn, NewRow, Predecessor, Row, RowLocation, SheetHeader, SortCriterion, SortDirection};
 pub use self::column_id::ColumnId;
-pub use self::error::Error;
+pub use self::error::{ApiError, Error, MappingError};
+pub use self::folder_id::FolderId;
+pub use self::pages::Pages;
+pub use self::retry_policy::RetryPolicy;
 pub use self::row_id::RowId;
+pub use self::row_mapping::{ColumnRef, FromCellValue, FromRow, RowReader, RowWriter, ToRow};
 pub use self::sheet_id::SheetId;
+pub use self::sheet_options::SheetOptions;
 pub use self::smartsheet::Smartsheet;
+pub use self::transfer_options::TransferOptions;
+pub use self::workspace_id::WorkspaceId;
 
 pub type Result<T> = ::std::result::Result<T, Error>;
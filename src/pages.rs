@@ -0,0 +1,56 @@
+use {Client, Result};
+use dto::IndexResult;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+
+// Lazily fetches pages of a list endpoint, the next page is requested only when needed
+#[derive(Debug)]
+pub struct Pages<T> {
+    client: Client,
+    urn: String,
+    page_size: u32,
+    next_page: Option<u32>,
+    item: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned> Pages<T> {
+    crate fn new(client: Client, urn: String, page_size: u32) -> Self {
+        Pages {
+            client,
+            urn,
+            page_size,
+            next_page: Some(1),
+            item: PhantomData,
+        }
+    }
+
+    pub fn fetch_all(self) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        for page in self {
+            items.extend(page?.into_data());
+        }
+        Ok(items)
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for Pages<T> {
+    type Item = Result<IndexResult<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let page_number = self.next_page?;
+        let result = self.client.fetch_page(&self.urn, page_number, self.page_size);
+        self.next_page = match result {
+            Ok(ref page) if has_next_page(page, page_number, self.page_size) => Some(page_number + 1),
+            _ => None,
+        };
+        Some(result)
+    }
+}
+
+// Without totalPages only a full page may be followed by another one
+crate fn has_next_page<T: DeserializeOwned>(page: &IndexResult<T>, page_number: u32, page_size: u32) -> bool {
+    match page.get_total_pages() {
+        Some(total_pages) => page_number < total_pages,
+        None => page.get_data().len() >= page_size as usize,
+    }
+}
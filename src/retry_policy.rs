@@ -0,0 +1,152 @@
+use std::cmp;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+const DEFAULT_INITIAL_BACKOFF_MS: u64 = 500;
+const DEFAULT_MAX_BACKOFF_MS: u64 = 30_000;
+const DEFAULT_MAX_RETRY_AFTER_MS: u64 = 60_000;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    max_retry_after: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        RetryPolicy {
+            max_attempts: cmp::max(max_attempts, 1),
+            initial_backoff,
+            max_backoff,
+            max_retry_after: Duration::from_millis(DEFAULT_MAX_RETRY_AFTER_MS),
+        }
+    }
+
+    // Longest wait accepted from the Retry-After header
+    pub fn with_max_retry_after(self, max_retry_after: Duration) -> Self {
+        RetryPolicy {
+            max_retry_after,
+            ..self
+        }
+    }
+
+    pub fn no_retry() -> Self {
+        Self::new(1, Duration::from_millis(0), Duration::from_millis(0))
+    }
+
+    pub fn get_max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    crate fn can_retry(&self, attempt: u32) -> bool {
+        attempt < self.max_attempts
+    }
+
+    // Exponential backoff with "equal jitter": half of the delay is fixed, the other half is random
+    crate fn get_backoff(&self, attempt: u32) -> Duration {
+        let exponent = cmp::min(attempt.saturating_sub(1), 31);
+        let backoff = self.initial_backoff.checked_mul(1 << exponent)
+            .map_or(self.max_backoff, |backoff| cmp::min(backoff, self.max_backoff));
+        let half_ms = to_millis(backoff) / 2;
+        Duration::from_millis(half_ms + random_below(half_ms + 1))
+    }
+
+    crate fn get_delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        match retry_after {
+            Some(retry_after) => cmp::min(retry_after, self.max_retry_after),
+            None => self.get_backoff(attempt),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_MAX_ATTEMPTS,
+            Duration::from_millis(DEFAULT_INITIAL_BACKOFF_MS),
+            Duration::from_millis(DEFAULT_MAX_BACKOFF_MS),
+        )
+    }
+}
+
+fn to_millis(duration: Duration) -> u64 {
+    duration.as_secs()
+        .saturating_mul(1000)
+        .saturating_add(u64::from(duration.subsec_millis()))
+}
+
+fn random_below(bound: u64) -> u64 {
+    RandomState::new()
+        .build_hasher()
+        .finish() % bound
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod get_backoff {
+        use super::*;
+
+        fn create_policy() -> RetryPolicy {
+            RetryPolicy::new(10, Duration::from_millis(100), Duration::from_millis(1000))
+        }
+
+        #[test]
+        fn grows_exponentially() {
+            let policy = create_policy();
+
+            for &(attempt, max_ms) in &[(1, 100), (2, 200), (3, 400), (4, 800)] {
+                let actual = to_millis(policy.get_backoff(attempt));
+
+                assert!(actual >= max_ms / 2 && actual <= max_ms, "Invalid backoff {} for attempt {}", actual, attempt);
+            }
+        }
+
+        #[test]
+        fn is_limited_by_max_backoff() {
+            let policy = create_policy();
+
+            let actual = to_millis(policy.get_backoff(40));
+
+            assert!(actual >= 500 && actual <= 1000, "Invalid backoff {}", actual);
+        }
+    }
+
+    mod get_delay {
+        use super::*;
+
+        #[test]
+        fn limits_retry_after() {
+            let policy = RetryPolicy::default()
+                .with_max_retry_after(Duration::from_secs(10));
+
+            assert_eq!(Duration::from_secs(5), policy.get_delay(1, Some(Duration::from_secs(5))));
+            assert_eq!(Duration::from_secs(10), policy.get_delay(1, Some(Duration::from_secs(3600))));
+        }
+    }
+
+    mod can_retry {
+        use super::*;
+
+        #[test]
+        fn allows_attempts_up_to_max() {
+            let policy = RetryPolicy::new(3, Duration::from_millis(0), Duration::from_millis(0));
+
+            assert!(policy.can_retry(1));
+            assert!(policy.can_retry(2));
+            assert!(!policy.can_retry(3));
+        }
+
+        #[test]
+        fn never_allows_when_retry_is_disabled() {
+            let policy = RetryPolicy::no_retry();
+
+            assert!(!policy.can_retry(1));
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt::{Display, Error as FmtError, Formatter};
+
 #[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 #[serde(transparent)]
 pub struct RowId {
@@ -9,3 +11,9 @@ impl From<u64> for RowId {
         RowId { id }
     }
 }
+
+impl Display for RowId {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        self.id.fmt(f)
+    }
+}
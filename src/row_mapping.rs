@@ -0,0 +1,296 @@
+use {Cell, CellValue, Column, ColumnId, Contact, Row, RowId};
+use error::MappingError;
+use std::{i64, u64};
+use std::result::Result as StdResult;
+
+pub trait FromRow: Sized {
+    fn from_row(row: &RowReader) -> StdResult<Self, MappingError>;
+}
+
+pub trait ToRow {
+    fn to_row(&self, row: &mut RowWriter) -> StdResult<(), MappingError>;
+}
+
+// On failure returns a description of the expected value
+pub trait FromCellValue: Sized {
+    fn from_cell_value(value: &CellValue) -> StdResult<Self, &'static str>;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColumnRef<'a> {
+    Title(&'a str),
+    Id(ColumnId),
+}
+
+impl<'a> From<&'a str> for ColumnRef<'a> {
+    fn from(title: &'a str) -> Self {
+        ColumnRef::Title(title)
+    }
+}
+
+impl<'a> From<ColumnId> for ColumnRef<'a> {
+    fn from(column_id: ColumnId) -> Self {
+        ColumnRef::Id(column_id)
+    }
+}
+
+pub struct RowReader<'a> {
+    row: &'a Row,
+    columns: &'a [Column],
+}
+
+impl<'a> RowReader<'a> {
+    crate fn new(row: &'a Row, columns: &'a [Column]) -> Self {
+        RowReader {
+            row,
+            columns,
+        }
+    }
+
+    pub fn get_row_id(&self) -> RowId {
+        self.row.get_row_id()
+    }
+
+    // Cells missing in the row are read as empty
+    pub fn get<'b, T: FromCellValue>(&self, column: impl Into<ColumnRef<'b>>) -> StdResult<T, MappingError> {
+        let column = find_column(self.columns, column.into())?;
+        let value = self.row.get_cell_value(&column.get_column_id())
+            .unwrap_or(&CellValue::Empty);
+        T::from_cell_value(value)
+            .map_err(|expected| match *value {
+                CellValue::Empty => MappingError::MissingValue(column.get_title().to_string()),
+                _ => MappingError::InvalidValue {
+                    column: column.get_title().to_string(),
+                    expected,
+                    actual: value.clone(),
+                },
+            })
+    }
+}
+
+pub struct RowWriter<'a> {
+    columns: &'a [Column],
+    cells: Vec<Cell>,
+}
+
+impl<'a> RowWriter<'a> {
+    crate fn new(columns: &'a [Column]) -> Self {
+        RowWriter {
+            columns,
+            cells: Vec::new(),
+        }
+    }
+
+    pub fn set<'b>(&mut self, column: impl Into<ColumnRef<'b>>, value: impl Into<CellValue>) -> StdResult<(), MappingError> {
+        let column_id = find_column(self.columns, column.into())?
+            .get_column_id();
+        self.cells.push(Cell::new(column_id, value.into()));
+        Ok(())
+    }
+
+    crate fn into_cells(self) -> Vec<Cell> {
+        self.cells
+    }
+}
+
+fn find_column<'a>(columns: &'a [Column], column: ColumnRef) -> StdResult<&'a Column, MappingError> {
+    let found = match column {
+        ColumnRef::Title(title) => columns.iter().find(|column| column.get_title() == title),
+        ColumnRef::Id(ref column_id) => columns.iter().find(|column| &column.get_column_id() == column_id),
+    };
+    found.ok_or_else(|| match column {
+        ColumnRef::Title(title) => MappingError::UnknownColumn(title.to_string()),
+        ColumnRef::Id(column_id) => MappingError::UnknownColumn(column_id.to_string()),
+    })
+}
+
+impl FromCellValue for CellValue {
+    fn from_cell_value(value: &CellValue) -> StdResult<Self, &'static str> {
+        Ok(value.clone())
+    }
+}
+
+impl FromCellValue for String {
+    fn from_cell_value(value: &CellValue) -> StdResult<Self, &'static str> {
+        match *value {
+            CellValue::Text(ref text)
+            | CellValue::Date(ref text)
+            | CellValue::DateTime(ref text) => Ok(text.clone()),
+            _ => Err("text"),
+        }
+    }
+}
+
+impl FromCellValue for f64 {
+    fn from_cell_value(value: &CellValue) -> StdResult<Self, &'static str> {
+        match *value {
+            CellValue::Number(number) => Ok(number),
+            _ => Err("number"),
+        }
+    }
+}
+
+impl FromCellValue for i64 {
+    fn from_cell_value(value: &CellValue) -> StdResult<Self, &'static str> {
+        match *value {
+            // Upper bound 2^63 is exact as f64, i64::MAX is not
+            CellValue::Number(number) if is_integer_in(number, i64::MIN as f64, -(i64::MIN as f64)) => Ok(number as i64),
+            _ => Err("integer"),
+        }
+    }
+}
+
+impl FromCellValue for u64 {
+    fn from_cell_value(value: &CellValue) -> StdResult<Self, &'static str> {
+        match *value {
+            // u64::MAX rounds up to 2^64 as f64
+            CellValue::Number(number) if is_integer_in(number, 0.0, u64::MAX as f64) => Ok(number as u64),
+            _ => Err("non-negative integer"),
+        }
+    }
+}
+
+// Casting out of range floats would saturate silently
+fn is_integer_in(number: f64, min: f64, bound: f64) -> bool {
+    number.fract() == 0.0 && number >= min && number < bound
+}
+
+impl FromCellValue for bool {
+    fn from_cell_value(value: &CellValue) -> StdResult<Self, &'static str> {
+        match *value {
+            CellValue::Bool(boolean) => Ok(boolean),
+            _ => Err("boolean"),
+        }
+    }
+}
+
+impl FromCellValue for Contact {
+    fn from_cell_value(value: &CellValue) -> StdResult<Self, &'static str> {
+        match *value {
+            CellValue::Contact(ref contact) => Ok(contact.clone()),
+            // Contact columns keep names without email as text
+            CellValue::Text(ref name) => Ok(Contact::new_with_parts(None, Some(name.clone()))),
+            _ => Err("contact"),
+        }
+    }
+}
+
+impl FromCellValue for Vec<Contact> {
+    fn from_cell_value(value: &CellValue) -> StdResult<Self, &'static str> {
+        match *value {
+            CellValue::Contact(ref contact) => Ok(vec![contact.clone()]),
+            CellValue::MultiContact(ref contacts) => Ok(contacts.clone()),
+            _ => Err("contacts"),
+        }
+    }
+}
+
+impl FromCellValue for Vec<String> {
+    fn from_cell_value(value: &CellValue) -> StdResult<Self, &'static str> {
+        match *value {
+            CellValue::Text(ref text) => Ok(vec![text.clone()]),
+            CellValue::MultiPicklist(ref options) => Ok(options.clone()),
+            _ => Err("options"),
+        }
+    }
+}
+
+impl<T: FromCellValue> FromCellValue for Option<T> {
+    fn from_cell_value(value: &CellValue) -> StdResult<Self, &'static str> {
+        match *value {
+            CellValue::Empty => Ok(None),
+            _ => T::from_cell_value(value).map(Some),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    fn create_columns() -> Vec<Column> {
+        serde_json::from_value(json!([
+                {
+                    "id": 21,
+                    "title": "my_column"
+                },
+                {
+                    "id": 22,
+                    "title": "other_column"
+                }
+            ])).unwrap()
+    }
+
+    fn create_row(value: CellValue) -> Row {
+        Row::new(RowId::from(31), Cell::new(ColumnId::from(21), value))
+    }
+
+    mod row_reader {
+        use super::*;
+
+        #[test]
+        fn reads_by_title_and_id() {
+            let columns = create_columns();
+            let row = create_row(CellValue::Number(3.0));
+            let reader = RowReader::new(&row, &columns);
+
+            assert_eq!(Ok(3), reader.get::<u64>("my_column"));
+            assert_eq!(Ok(3.0), reader.get::<f64>(ColumnId::from(21)));
+            assert_eq!(Ok(None), reader.get::<Option<String>>("other_column"));
+        }
+
+        #[test]
+        fn reports_typed_errors() {
+            let columns = create_columns();
+            let row = create_row(CellValue::Number(3.5));
+            let reader = RowReader::new(&row, &columns);
+
+            assert_eq!(Err(MappingError::UnknownColumn("nonexistent".to_string())), reader.get::<String>("nonexistent"));
+            assert_eq!(Err(MappingError::MissingValue("other_column".to_string())), reader.get::<String>("other_column"));
+            let expected = MappingError::InvalidValue {
+                column: "my_column".to_string(),
+                expected: "integer",
+                actual: CellValue::Number(3.5),
+            };
+            assert_eq!(Err(expected), reader.get::<i64>("my_column"));
+        }
+
+        #[test]
+        fn rejects_integers_out_of_range() {
+            let columns = create_columns();
+            let row = create_row(CellValue::Number(1e20));
+            let reader = RowReader::new(&row, &columns);
+
+            let expected = MappingError::InvalidValue {
+                column: "my_column".to_string(),
+                expected: "integer",
+                actual: CellValue::Number(1e20),
+            };
+            assert_eq!(Err(expected), reader.get::<i64>("my_column"));
+            assert!(reader.get::<u64>("my_column").is_err());
+            assert_eq!(Ok(1e20), reader.get::<f64>("my_column"));
+        }
+    }
+
+    mod row_writer {
+        use super::*;
+
+        #[test]
+        fn writes_cells() {
+            let columns = create_columns();
+            let mut writer = RowWriter::new(&columns);
+
+            writer.set("my_column", "data").unwrap();
+            writer.set(ColumnId::from(22), None::<f64>).unwrap();
+            let actual = writer.set("nonexistent", 1.0);
+
+            assert_eq!(Err(MappingError::UnknownColumn("nonexistent".to_string())), actual);
+            let cells = writer.into_cells();
+            assert_eq!(2, cells.len());
+            assert_eq!(&CellValue::from("data"), cells[0].get_value());
+            assert_eq!(ColumnId::from(22), cells[1].get_column_id());
+            assert_eq!(&CellValue::Empty, cells[1].get_value());
+        }
+    }
+}
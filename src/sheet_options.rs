@@ -0,0 +1,123 @@
+use {ColumnId, RowId};
+use std::cmp;
+
+// Object values and at least level 2 are always requested, cells are interpreted using them
+const REQUIRED_INCLUDE: &str = "objectValue";
+const MIN_LEVEL: u32 = 2;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SheetOptions {
+    column_ids: Vec<ColumnId>,
+    row_ids: Vec<RowId>,
+    row_numbers: Vec<u64>,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    level: Option<u32>,
+    filter_id: Option<u64>,
+    rows_modified_since: Option<String>,
+}
+
+impl SheetOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_column_ids(self, column_ids: Vec<ColumnId>) -> Self {
+        SheetOptions {
+            column_ids,
+            ..self
+        }
+    }
+
+    pub fn with_row_ids(self, row_ids: Vec<RowId>) -> Self {
+        SheetOptions {
+            row_ids,
+            ..self
+        }
+    }
+
+    pub fn with_row_numbers(self, row_numbers: Vec<u64>) -> Self {
+        SheetOptions {
+            row_numbers,
+            ..self
+        }
+    }
+
+    // E.g. "attachments", "discussions", "format", "rowPermalink" or "crossSheetReferences"
+    pub fn with_include<T: Into<String>>(mut self, include: T) -> Self {
+        self.include.push(include.into());
+        self
+    }
+
+    // E.g. "nonexistentCells"
+    pub fn with_exclude<T: Into<String>>(mut self, exclude: T) -> Self {
+        self.exclude.push(exclude.into());
+        self
+    }
+
+    // Levels below 2 are raised to 2
+    pub fn with_level(self, level: u32) -> Self {
+        SheetOptions {
+            level: Some(level),
+            ..self
+        }
+    }
+
+    pub fn with_filter_id(self, filter_id: u64) -> Self {
+        SheetOptions {
+            filter_id: Some(filter_id),
+            ..self
+        }
+    }
+
+    // Date and time in ISO-8601 format
+    pub fn with_rows_modified_since<T: Into<String>>(self, rows_modified_since: T) -> Self {
+        SheetOptions {
+            rows_modified_since: Some(rows_modified_since.into()),
+            ..self
+        }
+    }
+
+    // Sheet contains only some of the rows
+    crate fn is_partial(&self) -> bool {
+        !self.row_ids.is_empty() || !self.row_numbers.is_empty() || self.filter_id.is_some()
+            || self.rows_modified_since.is_some()
+    }
+
+    crate fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut include = vec![REQUIRED_INCLUDE];
+        include.extend(self.include.iter()
+            .map(String::as_str)
+            .filter(|include| *include != REQUIRED_INCLUDE));
+        let mut query = vec![
+            ("include", include.join(",")),
+            ("level", self.level.map_or(MIN_LEVEL, |level| cmp::max(level, MIN_LEVEL)).to_string()),
+        ];
+        if !self.column_ids.is_empty() {
+            query.push(("columnIds", join(&self.column_ids)));
+        }
+        if !self.row_ids.is_empty() {
+            query.push(("rowIds", join(&self.row_ids)));
+        }
+        if !self.row_numbers.is_empty() {
+            query.push(("rowNumbers", join(&self.row_numbers)));
+        }
+        if !self.exclude.is_empty() {
+            query.push(("exclude", self.exclude.join(",")));
+        }
+        if let Some(filter_id) = self.filter_id {
+            query.push(("filterId", filter_id.to_string()));
+        }
+        if let Some(ref rows_modified_since) = self.rows_modified_since {
+            query.push(("rowsModifiedSince", rows_modified_since.clone()));
+        }
+        query
+    }
+}
+
+fn join<T: ToString>(values: &[T]) -> String {
+    values.iter()
+        .map(T::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -1,55 +1,623 @@
-use {CellValue, Client, ColumnId, Error, Result, Row, RowId};
-use dto::{Cell, Sheet};
+use {BulkFailure, BulkResult, CellLink, CellValue, Client, Column, ColumnId, Error, FolderId, FromRow, Result, Row,
+     RowId, RowReader, RowWriter, SheetId, SheetOptions, ToRow, TransferOptions, WorkspaceId};
+use dto::{Cell, ColumnUpdate, NewColumn, NewRow, RowLocation, RowMove, Sheet, SheetHeader, SortCriterion};
+use std::collections::{BTreeMap, BTreeSet};
+
+const MAX_ROWS_PER_REQUEST: usize = 500;
+const MAX_CELLS_PER_REQUEST: usize = 5000;
+// Row ids are passed in the URL, which length is limited
+const MAX_ROW_IDS_PER_REQUEST: usize = 200;
 
 #[derive(Debug)]
 pub struct Smartsheet {
     client: Client,
     sheet: Sheet,
+    options: SheetOptions,
+    conditional_writes: bool,
 }
 
 impl Smartsheet {
     pub fn fetch(client: &Client, sheet_name: &str) -> Result<Smartsheet> {
-        let sheet_id = client.fetch_sheets()?
-            .iter()
-            .find(|sheet| sheet.get_name() == sheet_name)
-            .ok_or_else(|| Error::InvalidSheetName(sheet_name.to_string()))?
-            .get_sheet_id();
-        let sheet = client.fetch_sheet(&sheet_id)?;
+        Self::fetch_with_options(client, sheet_name, &SheetOptions::new())
+    }
+
+    pub fn fetch_with_options(client: &Client, sheet_name: &str, options: &SheetOptions) -> Result<Smartsheet> {
+        let sheet_id = find_sheet_id(&client.fetch_sheets()?, sheet_name)?;
+        Self::fetch_by_id_with_options(client, sheet_id, options)
+    }
+
+    pub fn fetch_by_id(client: &Client, sheet_id: SheetId) -> Result<Smartsheet> {
+        Self::fetch_by_id_with_options(client, sheet_id, &SheetOptions::new())
+    }
+
+    pub fn fetch_by_id_with_options(client: &Client, sheet_id: SheetId, options: &SheetOptions) -> Result<Smartsheet> {
+        let sheet = client.fetch_sheet(&sheet_id, options)?;
         Ok(Smartsheet {
             client: client.clone(),
             sheet,
+            options: options.clone(),
+            conditional_writes: false,
         })
     }
 
+    // Writes fail with Error::Conflict when the written rows were modified since the sheet was loaded or refreshed.
+    // Smartsheet has no conditional requests, so a change made between the check and the write is not detected.
+    // Changes of other rows are checked again on every write until refresh() is called, which also resolves conflicts.
+    // Only loaded rows are checked, writes to rows outside of a partially loaded sheet never conflict.
+    pub fn with_conditional_writes(self) -> Self {
+        Smartsheet {
+            conditional_writes: true,
+            ..self
+        }
+    }
+
+    pub fn get_version(&self) -> Option<u64> {
+        self.sheet.get_version()
+    }
+
+    // Returns false if the sheet hasn't changed since it was loaded or refreshed
+    pub fn refresh(&mut self) -> Result<bool> {
+        let sheet_id = self.sheet.get_sheet_id();
+        let version = self.client.fetch_sheet_version(&sheet_id)?;
+        if self.sheet.get_version() == Some(version) {
+            return Ok(false)
+        }
+        // Deleted rows can't be detected in a partially loaded sheet
+        let modified_since = if self.options.is_partial() {
+            None
+        } else {
+            self.sheet.get_modified_at().map(str::to_string)
+        };
+        let modified_since = match modified_since {
+            Some(modified_since) => modified_since,
+            None => return self.reload().map(|_| true),
+        };
+        let options = self.options.clone()
+            .with_rows_modified_since(modified_since);
+        let changed = self.client.fetch_sheet(&sheet_id, &options)?;
+        if changed.get_columns() != self.sheet.get_columns() {
+            return self.reload().map(|_| true)
+        }
+        self.sheet.merge(changed);
+        if self.sheet.get_total_row_count() != Some(self.sheet.get_row_count()) {
+            self.remove_deleted_rows()?;
+        }
+        Ok(true)
+    }
+
+    pub fn reload(&mut self) -> Result<()> {
+        let sheet_id = self.sheet.get_sheet_id();
+        self.sheet = self.client.fetch_sheet(&sheet_id, &self.options)?;
+        Ok(())
+    }
+
+    pub fn fetch_in_workspace(client: &Client, workspace_id: &WorkspaceId, sheet_name: &str) -> Result<Smartsheet> {
+        let sheet_id = find_sheet_id(&client.fetch_workspace_sheets(workspace_id)?, sheet_name)?;
+        Self::fetch_by_id(client, sheet_id)
+    }
+
+    pub fn fetch_in_folder(client: &Client, folder_id: &FolderId, sheet_name: &str) -> Result<Smartsheet> {
+        let sheet_id = find_sheet_id(&client.fetch_folder_sheets(folder_id)?, sheet_name)?;
+        Self::fetch_by_id(client, sheet_id)
+    }
+
     pub fn get_column_id(&self, title: &str) -> Option<ColumnId> {
         self.sheet.get_column_id(title)
     }
 
+    pub fn get_column(&self, column_id: &ColumnId) -> Option<&Column> {
+        self.sheet.get_column(column_id)
+    }
+
+    pub fn get_column_by_title(&self, title: &str) -> Option<&Column> {
+        self.sheet.get_column_by_title(title)
+    }
+
+    pub fn get_columns(&self) -> &[Column] {
+        self.sheet.get_columns()
+    }
+
+    pub fn add_columns(&mut self, index: usize, mut columns: Vec<NewColumn>) -> Result<Vec<ColumnId>> {
+        let sheet_id = self.sheet.get_sheet_id();
+        for column in &mut columns {
+            column.set_index(index);
+        }
+        let result = self.client.add_columns(&sheet_id, &columns)?;
+        self.sheet.advance_version(result.version);
+        let column_ids = result.result.iter()
+            .map(Column::get_column_id)
+            .collect();
+        self.refresh_columns()?;
+        Ok(column_ids)
+    }
+
+    pub fn update_column(&mut self, column_id: &ColumnId, update: ColumnUpdate) -> Result<()> {
+        let sheet_id = self.sheet.get_sheet_id();
+        let result = self.client.update_column(&sheet_id, column_id, &update)?;
+        self.sheet.advance_version(result.version);
+        self.refresh_columns()
+    }
+
+    pub fn delete_column(&mut self, column_id: &ColumnId) -> Result<()> {
+        let sheet_id = self.sheet.get_sheet_id();
+        let result = self.client.delete_column(&sheet_id, column_id)?;
+        self.sheet.advance_version(result.version);
+        self.sheet.remove_column_cells(column_id);
+        self.refresh_columns()
+    }
+
+    // Changing a single column may shift indexes of the others
+    fn refresh_columns(&mut self) -> Result<()> {
+        let sheet_id = self.sheet.get_sheet_id();
+        let columns = self.client.fetch_columns(&sheet_id)?;
+        self.sheet.set_columns(columns);
+        Ok(())
+    }
+
     pub fn find_row_id(&self, predicate: impl FnMut(&Row) -> bool) -> Option<RowId> {
         self.sheet.find_row_id(predicate)
     }
 
+    pub fn get_row(&self, row_id: &RowId) -> Option<&Row> {
+        self.sheet.get_row(row_id)
+    }
+
+    pub fn get_rows_iter(&self) -> impl Iterator<Item = &Row> {
+        self.sheet.get_rows_iter()
+    }
+
+    pub fn rows<T: FromRow>(&self) -> Result<Vec<(RowId, T)>> {
+        let columns = self.sheet.get_columns();
+        self.sheet.get_rows_iter()
+            .map(|row| {
+                let row_id = row.get_row_id();
+                T::from_row(&RowReader::new(row, columns))
+                    .map(|value| (row_id.clone(), value))
+                    .map_err(|error| Error::RowMapping { row_id: Some(row_id), error })
+            })
+            .collect()
+    }
+
+    // Cells for a new or updated row with the value written in
+    pub fn to_cells<T: ToRow>(&self, value: &T) -> Result<Vec<Cell>> {
+        self.write_cells(value, None)
+    }
+
+    pub fn push_typed_rows<T: ToRow>(&mut self, rows: &[(RowId, T)]) -> Result<()> {
+        let rows = rows.iter()
+            .map(|&(ref row_id, ref value)| {
+                let cells = self.write_cells(value, Some(row_id.clone()))?;
+                Ok(Row::new_with_cells(row_id.clone(), cells))
+            })
+            .collect::<Result<_>>()?;
+        self.push_rows(rows)
+    }
+
+    pub fn get_children(&self, row_id: &RowId) -> Vec<&Row> {
+        self.sheet.get_children(row_id)
+    }
+
+    pub fn get_ancestors(&self, row_id: &RowId) -> Vec<&Row> {
+        self.sheet.get_ancestors(row_id)
+    }
+
+    pub fn get_depth(&self, row_id: &RowId) -> Option<usize> {
+        self.sheet.get_depth(row_id)
+    }
+
+    pub fn walk_depth_first(&self) -> Vec<(usize, &Row)> {
+        self.sheet.walk_depth_first()
+    }
+
+    pub fn get_subtree(&self, row_id: &RowId) -> Vec<(usize, &Row)> {
+        self.sheet.get_subtree(row_id)
+    }
+
+    // Rows locked for the current user are rejected by Smartsheet on write
+    pub fn is_row_locked(&self, row_id: &RowId) -> bool {
+        self.sheet.get_row(row_id)
+            .map_or(false, Row::is_locked_for_user)
+    }
+
     pub fn push_cell_value(&mut self, column_id: &ColumnId, row_id: &RowId, cell_value: impl Into<CellValue>) -> Result<()> {
-        let sheet_id = self.sheet.get_sheet_id();
         let cell = Cell::new(column_id.clone(), cell_value.into());
         let row = Row::new(row_id.clone(), cell);
-        let updated_rows = self.client.update_cell(&sheet_id, row)?;
-        self.sheet.update_rows(updated_rows);
+        self.check_formula_overwrites(&row)?;
+        self.push_row(row)
+    }
+
+    pub fn push_cell_formula(&mut self, column_id: &ColumnId, row_id: &RowId, formula: impl Into<String>) -> Result<()> {
+        let cell = Cell::new_formula(column_id.clone(), formula);
+        self.push_row(Row::new(row_id.clone(), cell))
+    }
+
+    pub fn push_cell_link(&mut self, column_id: &ColumnId, row_id: &RowId, source: CellLink) -> Result<()> {
+        let cell = Cell::new_link(column_id.clone(), source);
+        let row = Row::new(row_id.clone(), cell);
+        self.check_formula_overwrites(&row)?;
+        self.push_row(row)
+    }
+
+    // Linked value stays in the cell as a regular value, cells without a link are left untouched
+    pub fn remove_cell_link(&mut self, column_id: &ColumnId, row_id: &RowId) -> Result<()> {
+        let value = match self.get_cell(column_id, row_id) {
+            Some(cell) if cell.get_link_in_from_cell().is_some() => cell.get_value().clone(),
+            _ => return Ok(()),
+        };
+        self.push_cell_value(column_id, row_id, value)
+    }
+
+    fn push_row(&mut self, row: Row) -> Result<()> {
+        self.check_conflicts(&[row.get_row_id()])?;
+        let sheet_id = self.sheet.get_sheet_id();
+        let result = self.client.update_cell(&sheet_id, row)?;
+        self.sheet.advance_version(result.version);
+        self.sheet.update_rows(result.result);
+        Ok(())
+    }
+
+    pub fn push_rows(&mut self, rows: Vec<Row>) -> Result<()> {
+        self.push_rows_with(rows, false)
+            .map(|_| ())
+    }
+
+    pub fn push_rows_partial(&mut self, rows: Vec<Row>) -> Result<BulkResult<RowId>> {
+        self.push_rows_with(rows, true)
+    }
+
+    fn push_rows_with(&mut self, rows: Vec<Row>, allow_partial_success: bool) -> Result<BulkResult<RowId>> {
+        for row in &rows {
+            self.check_formula_overwrites(row)?;
+        }
+        let row_ids: Vec<_> = rows.iter()
+            .map(Row::get_row_id)
+            .collect();
+        self.check_conflicts(&row_ids)?;
+        let sheet_id = self.sheet.get_sheet_id();
+        let mut bulk_result = BulkResult::new();
+        let mut index_offset = 0;
+        for chunk in split_into_chunks(&rows, |row| row.get_cells_iter().count()) {
+            let result = match self.client.update_rows(&sheet_id, chunk, allow_partial_success) {
+                Ok(result) => result,
+                // Earlier chunks are already written, so they are reported instead of the error
+                Err(error) => {
+                    if !allow_partial_success {
+                        return Err(error)
+                    }
+                    bulk_result.add_failed_chunk(chunk.iter().map(|row| Some(row.get_row_id())), index_offset, error);
+                    index_offset += chunk.len();
+                    continue
+                },
+            };
+            bulk_result.add_succeeded(result.result.iter().map(Row::get_row_id));
+            bulk_result.add_failed_items(result.failed_items, index_offset);
+            self.sheet.advance_version(result.version);
+            self.sheet.update_rows(result.result);
+            index_offset += chunk.len();
+        }
+        Ok(bulk_result)
+    }
+
+    pub fn add_rows(&mut self, rows: Vec<NewRow>) -> Result<Vec<RowId>> {
+        self.add_rows_with(rows, false)
+            .map(BulkResult::into_succeeded)
+    }
+
+    pub fn add_rows_partial(&mut self, rows: Vec<NewRow>) -> Result<BulkResult<RowId>> {
+        self.add_rows_with(rows, true)
+    }
+
+    fn add_rows_with(&mut self, rows: Vec<NewRow>, allow_partial_success: bool) -> Result<BulkResult<RowId>> {
+        // New rows are placed relative to their parents and siblings
+        let anchor_ids: Vec<_> = rows.iter()
+            .filter_map(|row| row.get_location().get_anchor_id())
+            .cloned()
+            .collect();
+        self.check_conflicts(&anchor_ids)?;
+        let sheet_id = self.sheet.get_sheet_id();
+        let mut bulk_result = BulkResult::new();
+        let mut index_offset = 0;
+        for chunk in split_into_chunks(&rows, |row| row.get_cells_iter().count()) {
+            let result = match self.client.add_rows(&sheet_id, chunk, allow_partial_success) {
+                Ok(result) => result,
+                Err(error) => {
+                    if !allow_partial_success {
+                        return Err(error)
+                    }
+                    bulk_result.add_failed_chunk(chunk.iter().map(|_| None), index_offset, error);
+                    index_offset += chunk.len();
+                    continue
+                },
+            };
+            bulk_result.add_succeeded(result.result.iter().map(Row::get_row_id));
+            bulk_result.add_failed_items(result.failed_items, index_offset);
+            self.sheet.advance_version(result.version);
+            self.sheet.update_rows(result.result);
+            index_offset += chunk.len();
+        }
+        Ok(bulk_result)
+    }
+
+    // Children of the row are moved along with it
+    pub fn move_row(&mut self, row_id: &RowId, location: RowLocation) -> Result<()> {
+        let sheet_id = self.sheet.get_sheet_id();
+        self.check_conflicts(&[row_id.clone()])?;
+        let row_move = RowMove::new(row_id.clone(), location);
+        let result = self.client.update_rows(&sheet_id, &[row_move], false)?;
+        self.sheet.advance_version(result.version);
+        self.sheet.update_rows(result.result);
+        Ok(())
+    }
+
+    // Returns new row ids by the old ones
+    pub fn move_rows(&mut self, row_ids: &[RowId], destination_id: &SheetId, options: &TransferOptions)
+            -> Result<BTreeMap<RowId, RowId>> {
+        self.check_conflicts(row_ids)?;
+        let sheet_id = self.sheet.get_sheet_id();
+        let row_mappings = self.client.move_rows(&sheet_id, row_ids, destination_id, options)?;
+        self.sheet.remove_rows(row_mappings.keys());
+        Ok(row_mappings)
+    }
+
+    pub fn move_rows_into(&mut self, row_ids: &[RowId], destination: &mut Smartsheet, options: &TransferOptions)
+            -> Result<BTreeMap<RowId, RowId>> {
+        let destination_id = destination.sheet.get_sheet_id();
+        let row_mappings = self.move_rows(row_ids, &destination_id, options)?;
+        destination.fetch_rows(row_mappings.values())?;
+        Ok(row_mappings)
+    }
+
+    pub fn copy_rows(&self, row_ids: &[RowId], destination_id: &SheetId, options: &TransferOptions)
+            -> Result<BTreeMap<RowId, RowId>> {
+        let sheet_id = self.sheet.get_sheet_id();
+        self.client.copy_rows(&sheet_id, row_ids, destination_id, options)
+    }
+
+    pub fn copy_rows_into(&self, row_ids: &[RowId], destination: &mut Smartsheet, options: &TransferOptions)
+            -> Result<BTreeMap<RowId, RowId>> {
+        let destination_id = destination.sheet.get_sheet_id();
+        let row_mappings = self.copy_rows(row_ids, &destination_id, options)?;
+        destination.fetch_rows(row_mappings.values())?;
+        Ok(row_mappings)
+    }
+
+    // Criteria are applied in the given order
+    pub fn sort_rows(&mut self, sort_criteria: &[SortCriterion]) -> Result<()> {
+        self.check_sheet_conflicts()?;
+        let sheet_id = self.sheet.get_sheet_id();
+        let sheet = self.client.sort_rows(&sheet_id, sort_criteria)?;
+        // Response contains the whole sheet with its new version, a sheet loaded with options is fetched again
+        if self.options == SheetOptions::new() {
+            self.sheet = sheet;
+            Ok(())
+        } else {
+            self.reload()
+        }
+    }
+
+    pub fn delete_rows(&mut self, row_ids: &[RowId], ignore_rows_not_found: bool) -> Result<Vec<RowId>> {
+        self.check_conflicts(row_ids)?;
+        let sheet_id = self.sheet.get_sheet_id();
+        let mut deleted_row_ids = Vec::with_capacity(row_ids.len());
+        for chunk in row_ids.chunks(MAX_ROW_IDS_PER_REQUEST) {
+            let result = self.client.delete_rows(&sheet_id, chunk, ignore_rows_not_found)?;
+            self.sheet.advance_version(result.version);
+            deleted_row_ids.extend(result.result);
+            self.sheet.remove_rows(chunk);
+        }
+        Ok(deleted_row_ids)
+    }
+
+    pub fn delete_rows_partial(&mut self, row_ids: &[RowId]) -> Result<BulkResult<RowId>> {
+        self.check_conflicts(row_ids)?;
+        let sheet_id = self.sheet.get_sheet_id();
+        let mut bulk_result = BulkResult::new();
+        for (chunk_index, chunk) in row_ids.chunks(MAX_ROW_IDS_PER_REQUEST).enumerate() {
+            let index_offset = chunk_index * MAX_ROW_IDS_PER_REQUEST;
+            let result = match self.client.delete_rows(&sheet_id, chunk, true) {
+                Ok(result) => result,
+                // Earlier chunks are already deleted, so they are reported instead of the error
+                Err(error) => {
+                    bulk_result.add_failed_chunk(chunk.iter().cloned().map(Some), index_offset, error);
+                    continue
+                },
+            };
+            self.sheet.advance_version(result.version);
+            let deleted_row_ids: BTreeSet<_> = result.result.into_iter().collect();
+            for (index, row_id) in chunk.iter().enumerate() {
+                if deleted_row_ids.contains(row_id) {
+                    bulk_result.add_succeeded(Some(row_id.clone()));
+                } else {
+                    let error = Error::RowNotDeleted(row_id.clone());
+                    bulk_result.add_failed(BulkFailure::new(index_offset + index, Some(row_id.clone()), error));
+                }
+            }
+            self.sheet.remove_rows(chunk);
+        }
+        Ok(bulk_result)
+    }
+
+    fn write_cells<T: ToRow>(&self, value: &T, row_id: Option<RowId>) -> Result<Vec<Cell>> {
+        let mut writer = RowWriter::new(self.sheet.get_columns());
+        match value.to_row(&mut writer) {
+            Ok(()) => Ok(writer.into_cells()),
+            Err(error) => Err(Error::RowMapping { row_id, error }),
+        }
+    }
+
+    fn check_conflicts(&self, row_ids: &[RowId]) -> Result<()> {
+        if row_ids.is_empty() {
+            return Ok(())
+        }
+        let version = match self.fetch_newer_version()? {
+            Some(version) => version,
+            None => return Ok(()),
+        };
+        let row_ids: BTreeSet<_> = row_ids.iter().cloned().collect();
+        let rows = self.fetch_modified_rows(&row_ids)?;
+        if rows.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Conflict { version, rows })
+        }
+    }
+
+    // Sorting moves all rows, so any change of the sheet is a conflict
+    fn check_sheet_conflicts(&self) -> Result<()> {
+        let version = match self.fetch_newer_version()? {
+            Some(version) => version,
+            None => return Ok(()),
+        };
+        let row_ids: BTreeSet<_> = self.sheet.get_rows_iter()
+            .map(Row::get_row_id)
+            .collect();
+        let rows = self.fetch_modified_rows(&row_ids)?;
+        Err(Error::Conflict { version, rows })
+    }
+
+    // None if the sheet wasn't changed since it was loaded or refreshed
+    fn fetch_newer_version(&self) -> Result<Option<u64>> {
+        if !self.conditional_writes {
+            return Ok(None)
+        }
+        let version = self.client.fetch_sheet_version(&self.sheet.get_sheet_id())?;
+        if self.sheet.get_version() == Some(version) {
+            Ok(None)
+        } else {
+            Ok(Some(version))
+        }
+    }
+
+    // Loaded rows changed by others, rows modified since the last known change are fetched at once
+    fn fetch_modified_rows(&self, row_ids: &BTreeSet<RowId>) -> Result<Vec<Row>> {
+        let sheet_id = self.sheet.get_sheet_id();
+        let fetched = match self.sheet.get_modified_at() {
+            Some(modified_at) => {
+                let options = SheetOptions::new()
+                    .with_rows_modified_since(modified_at);
+                self.client.fetch_sheet(&sheet_id, &options)?
+                    .into_rows()
+            },
+            None => {
+                let row_ids: Vec<_> = row_ids.iter().cloned().collect();
+                let mut rows = Vec::new();
+                for chunk in row_ids.chunks(MAX_ROW_IDS_PER_REQUEST) {
+                    rows.extend(self.client.fetch_rows(&sheet_id, chunk)?);
+                }
+                rows
+            },
+        };
+        let modified = fetched.into_iter()
+            .filter(|row| row_ids.contains(&row.get_row_id()))
+            .filter(|row| self.sheet.get_row(&row.get_row_id()).map_or(false, |cached| is_modified(cached, row)))
+            .collect();
+        Ok(modified)
+    }
+
+    // Lists ids of all rows, downloading only cells of a single column
+    fn remove_deleted_rows(&mut self) -> Result<()> {
+        let sheet_id = self.sheet.get_sheet_id();
+        let column_ids = self.sheet.get_columns().iter()
+            .take(1)
+            .map(Column::get_column_id)
+            .collect();
+        let options = SheetOptions::new()
+            .with_column_ids(column_ids)
+            .with_exclude("nonexistentCells");
+        let existing: BTreeSet<_> = self.client.fetch_sheet(&sheet_id, &options)?
+            .get_rows_iter()
+            .map(Row::get_row_id)
+            .collect();
+        let deleted: Vec<_> = self.sheet.get_rows_iter()
+            .map(Row::get_row_id)
+            .filter(|row_id| !existing.contains(row_id))
+            .collect();
+        self.sheet.remove_rows(&deleted);
+        Ok(())
+    }
+
+    fn fetch_rows<'a>(&mut self, row_ids: impl IntoIterator<Item = &'a RowId>) -> Result<()> {
+        let sheet_id = self.sheet.get_sheet_id();
+        let row_ids: Vec<_> = row_ids.into_iter()
+            .cloned()
+            .collect();
+        for chunk in row_ids.chunks(MAX_ROW_IDS_PER_REQUEST) {
+            let rows = self.client.fetch_rows(&sheet_id, chunk)?;
+            self.sheet.update_rows(rows);
+        }
+        Ok(())
+    }
+
+    fn check_formula_overwrites(&self, row: &Row) -> Result<()> {
+        let row_id = row.get_row_id();
+        for cell in row.get_cells_iter() {
+            if cell.get_formula().is_some() || cell.is_formula_overwrite_allowed() {
+                continue
+            }
+            let column_id = cell.get_column_id();
+            let has_formula = self.sheet.get_cell(&column_id, &row_id)
+                .and_then(Cell::get_formula)
+                .is_some();
+            if has_formula {
+                return Err(Error::FormulaOverwrite { column_id, row_id })
+            }
+        }
         Ok(())
     }
 
+    pub fn get_cell(&self, column_id: &ColumnId, row_id: &RowId) -> Option<&Cell> {
+        self.sheet.get_cell(column_id, row_id)
+    }
+
     pub fn get_cell_value(&self, column_id: &ColumnId, row_id: &RowId) -> Option<&CellValue> {
         self.sheet.get_cell_value(column_id, row_id)
     }
 }
 
+fn split_into_chunks<T>(rows: &[T], count_cells: impl Fn(&T) -> usize) -> Vec<&[T]> {
+    let mut chunks = Vec::new();
+    let mut chunk_start = 0;
+    let mut chunk_cells = 0;
+    for (index, row) in rows.iter().enumerate() {
+        let row_cells = count_cells(row);
+        let is_chunk_full = index - chunk_start == MAX_ROWS_PER_REQUEST
+            || chunk_cells + row_cells > MAX_CELLS_PER_REQUEST;
+        if index > chunk_start && is_chunk_full {
+            chunks.push(&rows[chunk_start..index]);
+            chunk_start = index;
+            chunk_cells = 0;
+        }
+        chunk_cells += row_cells;
+    }
+    if chunk_start < rows.len() {
+        chunks.push(&rows[chunk_start..]);
+    }
+    chunks
+}
+
+fn is_modified(cached: &Row, fetched: &Row) -> bool {
+    cached.get_version() != fetched.get_version() || cached.get_modified_at() != fetched.get_modified_at()
+}
+
+crate fn find_sheet_id(sheets: &[SheetHeader], sheet_name: &str) -> Result<SheetId> {
+    let mut found = sheets.iter()
+        .filter(|sheet| sheet.get_name() == sheet_name);
+    match (found.next(), found.next()) {
+        (Some(sheet), None) => Ok(sheet.get_sheet_id()),
+        (Some(_), Some(_)) => Err(Error::AmbiguousSheetName(sheet_name.to_string())),
+        (None, _) => Err(Error::InvalidSheetName(sheet_name.to_string())),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ApiError;
     use mockito::{self, Matcher, Mock};
+    use serde_json::Value;
 
     fn mock_sheets() -> Mock {
-        mockito::mock("GET", "/sheets?includeAll=true")
+        mockito::mock("GET", "/sheets?page=1&pageSize=100")
             .match_header("authorization", "Bearer TEST_TOKEN")
             .with_body(json!({
                         "data": [
@@ -63,7 +631,7 @@ mod tests {
     }
 
     fn mock_sheet() -> Mock {
-        mockito::mock("GET", "/sheets/11")
+        mockito::mock("GET", "/sheets/11?include=objectValue&level=2")
             .match_header("authorization", "Bearer TEST_TOKEN")
             .with_body(json!({
                     "id": 11,
@@ -75,7 +643,13 @@ mod tests {
                         },
                         {
                             "id": 22,
-                            "title": "other_column"
+                            "title": "other_column",
+                            "type": "PICKLIST",
+                            "index": 1,
+                            "options": ["option_1", "option_2"],
+                            "validation": true,
+                            "width": 150,
+                            "locked": true
                         }
                     ],
                     "rows": [
@@ -114,6 +688,16 @@ mod tests {
             .unwrap()
     }
 
+    fn create_smartsheet_with(sheet: Value) -> Smartsheet {
+        let sheet_id = sheet["id"].as_u64().unwrap();
+        let _mock_sheet = mockito::mock("GET", format!("/sheets/{}?include=objectValue&level=2", sheet_id).as_str())
+            .with_body(sheet.to_string())
+            .create();
+        let client = Client::new_mocked();
+        Smartsheet::fetch_by_id(&client, SheetId::from(sheet_id))
+            .unwrap()
+    }
+
     fn assert_cell_value(expected_str: &str, smartsheet: &Smartsheet, column_id: u64, row_id: u64) {
         let assert_name = format!("for column {} and row {}", column_id, row_id);
         let expected = &CellValue::from(expected_str);
@@ -122,6 +706,101 @@ mod tests {
         assert_eq!(expected, actual, "Invalid cell value {}", assert_name);
     }
 
+    // Ids don't follow the sheet order on purpose
+    fn create_plan_smartsheet() -> Smartsheet {
+        create_smartsheet_with(json!({
+                "id": 11,
+                "name": "my_sheet",
+                "columns": [],
+                "rows": [
+                    {
+                        "id": 35,
+                        "rowNumber": 1
+                    },
+                    {
+                        "id": 31,
+                        "rowNumber": 2,
+                        "parentId": 35
+                    },
+                    {
+                        "id": 34,
+                        "rowNumber": 3,
+                        "parentId": 31
+                    },
+                    {
+                        "id": 32,
+                        "rowNumber": 4,
+                        "parentId": 35,
+                        "siblingId": 31
+                    },
+                    {
+                        "id": 33,
+                        "rowNumber": 5,
+                        "siblingId": 35
+                    }
+                ]
+            }))
+    }
+
+    fn to_ids(rows: &[&Row]) -> Vec<u64> {
+        rows.iter()
+            .map(|row| row.get_row_id().to_string().parse().unwrap())
+            .collect()
+    }
+
+    fn to_depths_and_ids(rows: &[(usize, &Row)]) -> Vec<(usize, u64)> {
+        rows.iter()
+            .map(|&(depth, row)| (depth, row.get_row_id().to_string().parse().unwrap()))
+            .collect()
+    }
+
+    fn create_versioned_smartsheet() -> Smartsheet {
+        create_smartsheet_with(json!({
+                "id": 11,
+                "name": "my_sheet",
+                "version": 5,
+                "modifiedAt": "2018-10-01T10:00:00Z",
+                "totalRowCount": 2,
+                "columns": [
+                    {
+                        "id": 21,
+                        "title": "my_column"
+                    }
+                ],
+                "rows": [
+                    {
+                        "id": 31,
+                        "modifiedAt": "2018-10-01T09:00:00Z",
+                        "cells": [
+                            {
+                                "columnId": 21,
+                                "value": "data_21_31"
+                            }
+                        ]
+                    },
+                    {
+                        "id": 32,
+                        "modifiedAt": "2018-10-01T10:00:00Z",
+                        "cells": [
+                            {
+                                "columnId": 21,
+                                "value": "data_21_32"
+                            }
+                        ]
+                    }
+                ]
+            }))
+    }
+
+    fn mock_version(version: u64) -> Mock {
+        mockito::mock("GET", "/sheets/11/version")
+            .match_header("authorization", "Bearer TEST_TOKEN")
+            .with_body(json!({
+                    "version": version
+                }).to_string())
+            .create()
+    }
+
     mod fetch {
         use super::*;
 
@@ -159,102 +838,2074 @@ mod tests {
                 assert_eq!(expected, actual);
             }
         }
-    }
 
-    mod get_column_id {
-        use super::*;
-
-        mod when_column_exists {
+        mod when_sheet_name_is_ambiguous {
             use super::*;
 
             #[test]
-            fn then_returns_id() {
-                let smartsheet = create_smartsheet();
+            fn then_returns_error() {
+                let mock = mockito::mock("GET", "/sheets?page=1&pageSize=100")
+                    .with_body(json!({
+                            "data": [
+                                {
+                                    "id": 11,
+                                    "name": "my_sheet"
+                                },
+                                {
+                                    "id": 12,
+                                    "name": "my_sheet"
+                                }
+                            ]
+                        }).to_string())
+                    .create();
+                let client = Client::new_mocked();
 
-                let result = smartsheet.get_column_id("my_column");
+                let result = Smartsheet::fetch(&client, "my_sheet");
 
-                let expected = ColumnId::from(21);
-                let actual = result.unwrap();
+                mock.assert();
+                let actual = result.unwrap_err();
+                let expected = Error::AmbiguousSheetName("my_sheet".to_string());
                 assert_eq!(expected, actual);
             }
         }
+    }
 
-        mod when_column_does_not_exist {
-            use super::*;
+    mod fetch_by_id {
+        use super::*;
 
-            #[test]
-            fn then_returns_none() {
-                let smartsheet = create_smartsheet();
+        #[test]
+        fn does_not_list_sheets() {
+            let mock_sheets = mock_sheets().expect(0);
+            let mock_sheet = mock_sheet();
+            let client = Client::new_mocked();
 
-                let result = smartsheet.get_column_id("nonexistent");
+            let result = Smartsheet::fetch_by_id(&client, SheetId::from(11));
 
-                assert!(result.is_none());
-            }
+            mock_sheets.assert();
+            mock_sheet.assert();
+            assert_eq!(SheetId::from(11), result.unwrap().sheet.get_sheet_id());
         }
     }
 
-    mod find_row_id {
+    mod fetch_by_id_with_options {
         use super::*;
+        use SheetOptions;
 
-        mod when_row_is_found {
-            use super::*;
-
-            #[test]
-            fn then_returns_id() {
-                let smartsheet = create_smartsheet();
-                let column_id = &ColumnId::from(21);
-                let searched = &CellValue::from("data_21_31");
+        #[test]
+        fn loads_only_requested_parts() {
+            let mock_sheet = mockito::mock("GET", "/sheets/11?include=objectValue%2Cformat&level=2&columnIds=21&rowNumbers=2%2C3\
+                        &exclude=nonexistentCells&filterId=91&rowsModifiedSince=2018-10-01T00%3A00%3A00Z")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "id": 11,
+                        "name": "my_sheet",
+                        "columns": [
+                            {
+                                "id": 21,
+                                "title": "my_column"
+                            }
+                        ],
+                        "rows": [
+                            {
+                                "id": 32,
+                                "rowNumber": 2,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "data_21_32"
+                                    }
+                                ]
+                            }
+                        ]
+                    }).to_string())
+                .create();
+            let client = Client::new_mocked();
+            let options = SheetOptions::new()
+                .with_column_ids(vec![ColumnId::from(21)])
+                .with_row_numbers(vec![2, 3])
+                .with_include("format")
+                .with_include("objectValue")
+                .with_exclude("nonexistentCells")
+                .with_level(1)
+                .with_filter_id(91)
+                .with_rows_modified_since("2018-10-01T00:00:00Z");
 
-                let result = smartsheet.find_row_id(|row| row.get_cell_value(column_id) == Some(searched));
+            let result = Smartsheet::fetch_by_id_with_options(&client, SheetId::from(11), &options);
 
-                let expected = RowId::from(31);
-                let actual = result.unwrap();
-                assert_eq!(expected, actual);
-            }
+            mock_sheet.assert();
+            let smartsheet = result.unwrap();
+            assert_eq!(1, smartsheet.get_columns().len());
+            assert_cell_value("data_21_32", &smartsheet, 21, 32);
+            assert_eq!(Some(2), smartsheet.get_row(&RowId::from(32)).unwrap().get_row_number());
         }
+    }
 
-        mod when_row_is_not_found {
-            use super::*;
+    mod fetch_in_folder {
+        use super::*;
+        use FolderId;
+
+        #[test]
+        fn finds_sheet_in_folder() {
+            let mock_folder = mockito::mock("GET", "/folders/71")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "id": 71,
+                        "name": "my_folder",
+                        "sheets": [
+                            {
+                                "id": 11,
+                                "name": "my_sheet"
+                            }
+                        ]
+                    }).to_string())
+                .create();
+            let mock_sheet = mock_sheet();
+            let client = Client::new_mocked();
+
+            let result = Smartsheet::fetch_in_folder(&client, &FolderId::from(71), "my_sheet");
+
+            mock_folder.assert();
+            mock_sheet.assert();
+            assert_eq!(SheetId::from(11), result.unwrap().sheet.get_sheet_id());
+        }
+    }
+
+    mod fetch_in_workspace {
+        use super::*;
+        use WorkspaceId;
+
+        #[test]
+        fn reports_missing_sheet() {
+            let mock_workspace = mockito::mock("GET", "/workspaces/81")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "id": 81,
+                        "name": "my_workspace"
+                    }).to_string())
+                .create();
+            let client = Client::new_mocked();
+
+            let result = Smartsheet::fetch_in_workspace(&client, &WorkspaceId::from(81), "my_sheet");
+
+            mock_workspace.assert();
+            let expected = Error::InvalidSheetName("my_sheet".to_string());
+            assert_eq!(expected, result.unwrap_err());
+        }
+    }
+
+    mod refresh {
+        use super::*;
+
+        #[test]
+        fn skips_unchanged_sheet() {
+            let mut smartsheet = create_versioned_smartsheet();
+            let mock_version = mock_version(5);
+
+            let result = smartsheet.refresh();
+
+            mock_version.assert();
+            assert_eq!(Ok(false), result);
+        }
+
+        #[test]
+        fn merges_changed_rows_and_removes_deleted() {
+            let mut smartsheet = create_versioned_smartsheet();
+            let mock_version = mock_version(7);
+            let mock_changed = mockito::mock("GET",
+                    "/sheets/11?include=objectValue&level=2&rowsModifiedSince=2018-10-01T10%3A00%3A00Z")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "id": 11,
+                        "name": "my_sheet",
+                        "version": 7,
+                        "modifiedAt": "2018-10-02T10:00:00Z",
+                        "totalRowCount": 2,
+                        "columns": [
+                            {
+                                "id": 21,
+                                "title": "my_column"
+                            }
+                        ],
+                        "rows": [
+                            {
+                                "id": 33,
+                                "siblingId": 31,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "data_21_33"
+                                    }
+                                ]
+                            }
+                        ]
+                    }).to_string())
+                .create();
+            let mock_row_ids = mockito::mock("GET",
+                    "/sheets/11?include=objectValue&level=2&columnIds=21&exclude=nonexistentCells")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "id": 11,
+                        "name": "my_sheet",
+                        "columns": [
+                            {
+                                "id": 21,
+                                "title": "my_column"
+                            }
+                        ],
+                        "rows": [
+                            {
+                                "id": 31
+                            },
+                            {
+                                "id": 33
+                            }
+                        ]
+                    }).to_string())
+                .create();
+
+            let result = smartsheet.refresh();
+
+            mock_version.assert();
+            mock_changed.assert();
+            mock_row_ids.assert();
+            assert_eq!(Ok(true), result);
+            assert_cell_value("data_21_31", &smartsheet, 21, 31);
+            assert_cell_value("data_21_33", &smartsheet, 21, 33);
+            assert!(smartsheet.get_row(&RowId::from(32)).is_none());
+            assert_eq!(Some(7), smartsheet.sheet.get_version());
+        }
+
+        #[test]
+        fn reloads_sheet_with_changed_columns() {
+            let mut smartsheet = create_versioned_smartsheet();
+            let mock_version = mock_version(6);
+            let mock_changed = mockito::mock("GET",
+                    "/sheets/11?include=objectValue&level=2&rowsModifiedSince=2018-10-01T10%3A00%3A00Z")
+                .with_body(json!({
+                        "id": 11,
+                        "name": "my_sheet",
+                        "version": 6,
+                        "columns": [
+                            {
+                                "id": 21,
+                                "title": "renamed_column"
+                            }
+                        ],
+                        "rows": []
+                    }).to_string())
+                .create();
+            let mock_sheet = mock_sheet();
+
+            let result = smartsheet.refresh();
+
+            mock_version.assert();
+            mock_changed.assert();
+            mock_sheet.assert();
+            assert_eq!(Ok(true), result);
+            assert_eq!(Some(ColumnId::from(22)), smartsheet.get_column_id("other_column"));
+        }
+    }
+
+    mod conditional_writes {
+        use super::*;
+        use {RowLocation, SortCriterion};
+
+        fn mock_push(version: u64) -> Mock {
+            mockito::mock("PUT", "/sheets/11/rows")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "version": version,
+                        "result": [
+                            {
+                                "id": 31,
+                                "modifiedAt": "2018-10-01T11:00:00Z",
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "new_data"
+                                    }
+                                ]
+                            }
+                        ]
+                    }).to_string())
+                .create()
+        }
+
+        fn mock_modified_rows(rows: Value) -> Mock {
+            mockito::mock("GET", "/sheets/11?include=objectValue&level=2&rowsModifiedSince=2018-10-01T10%3A00%3A00Z")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "id": 11,
+                        "name": "my_sheet",
+                        "columns": [
+                            {
+                                "id": 21,
+                                "title": "my_column"
+                            }
+                        ],
+                        "rows": rows
+                    }).to_string())
+                .create()
+        }
+
+        fn create_modified_row(row_id: u64) -> Value {
+            json!({
+                "id": row_id,
+                "modifiedAt": "2018-10-01T10:30:00Z",
+                "cells": [
+                    {
+                        "columnId": 21,
+                        "value": "other_data"
+                    }
+                ]
+            })
+        }
+
+        #[test]
+        fn writes_to_unchanged_sheet() {
+            let mut smartsheet = create_versioned_smartsheet()
+                .with_conditional_writes();
+            let mock_version = mock_version(5);
+            let mock_push = mock_push(6);
+
+            let result = smartsheet.push_cell_value(&ColumnId::from(21), &RowId::from(31), "new_data");
+
+            mock_version.assert();
+            mock_push.assert();
+            assert_eq!(Ok(()), result);
+            assert_cell_value("new_data", &smartsheet, 21, 31);
+            assert_eq!(Some(6), smartsheet.get_version());
+        }
+
+        #[test]
+        fn rejects_write_to_modified_row() {
+            let mut smartsheet = create_versioned_smartsheet()
+                .with_conditional_writes();
+            let mock_version = mock_version(6);
+            let mock_modified_rows = mock_modified_rows(json!([create_modified_row(31)]));
+            let mock_push = mock_push(7)
+                .expect(0);
+
+            let result = smartsheet.push_cell_value(&ColumnId::from(21), &RowId::from(31), "new_data");
+
+            mock_version.assert();
+            mock_modified_rows.assert();
+            mock_push.assert();
+            match result {
+                Err(Error::Conflict { version: 6, ref rows }) => {
+                    assert_eq!(1, rows.len());
+                    assert_eq!(RowId::from(31), rows[0].get_row_id());
+                    assert_eq!(Some("2018-10-01T10:30:00Z"), rows[0].get_modified_at());
+                },
+                _ => panic!("Invalid result: '{:?}'", result),
+            }
+            assert_cell_value("data_21_31", &smartsheet, 21, 31);
+        }
+
+        #[test]
+        fn writes_when_only_other_rows_changed() {
+            let mut smartsheet = create_versioned_smartsheet()
+                .with_conditional_writes();
+            let mock_version = mock_version(6);
+            let mock_modified_rows = mock_modified_rows(json!([create_modified_row(32)]));
+            let mock_push = mock_push(7);
+
+            let result = smartsheet.push_cell_value(&ColumnId::from(21), &RowId::from(31), "new_data");
+
+            mock_version.assert();
+            mock_modified_rows.assert();
+            mock_push.assert();
+            assert_eq!(Ok(()), result);
+            assert_cell_value("new_data", &smartsheet, 21, 31);
+            // Skipping version 6 would hide the other change from refresh
+            assert_eq!(Some(5), smartsheet.get_version());
+        }
+
+        #[test]
+        fn rejects_sorting_modified_rows() {
+            let mut smartsheet = create_versioned_smartsheet()
+                .with_conditional_writes();
+            let mock_version = mock_version(6);
+            let mock_modified_rows = mock_modified_rows(json!([create_modified_row(32)]));
+            let mock_sort = mockito::mock("POST", "/sheets/11/sort?include=objectValue&level=2")
+                .expect(0)
+                .create();
+
+            let result = smartsheet.sort_rows(&[SortCriterion::ascending(ColumnId::from(21))]);
+
+            mock_version.assert();
+            mock_modified_rows.assert();
+            mock_sort.assert();
+            match result {
+                Err(Error::Conflict { version: 6, ref rows }) => assert_eq!(RowId::from(32), rows[0].get_row_id()),
+                _ => panic!("Invalid result: '{:?}'", result),
+            }
+        }
+
+        #[test]
+        fn rejects_sorting_changed_sheet() {
+            let mut smartsheet = create_versioned_smartsheet()
+                .with_conditional_writes();
+            let mock_version = mock_version(6);
+            let mock_modified_rows = mock_modified_rows(json!([]));
+            let mock_sort = mockito::mock("POST", "/sheets/11/sort?include=objectValue&level=2")
+                .expect(0)
+                .create();
+
+            let result = smartsheet.sort_rows(&[SortCriterion::ascending(ColumnId::from(21))]);
+
+            mock_version.assert();
+            mock_modified_rows.assert();
+            mock_sort.assert();
+            assert_eq!(Err(Error::Conflict { version: 6, rows: vec![] }), result);
+        }
+
+        #[test]
+        fn checks_other_changes_again_on_next_write() {
+            let mut smartsheet = create_versioned_smartsheet()
+                .with_conditional_writes();
+            let mock_version = mock_version(6)
+                .expect(2);
+            let mock_modified_rows = mock_modified_rows(json!([create_modified_row(32)]))
+                .expect(2);
+            let mock_push = mock_push(7)
+                .expect(2);
+
+            let first = smartsheet.push_cell_value(&ColumnId::from(21), &RowId::from(31), "new_data");
+            let second = smartsheet.push_cell_value(&ColumnId::from(21), &RowId::from(31), "new_data");
+
+            mock_version.assert();
+            mock_modified_rows.assert();
+            mock_push.assert();
+            assert_eq!(Ok(()), first);
+            assert_eq!(Ok(()), second);
+            assert_eq!(Some(5), smartsheet.get_version());
+        }
+
+        #[test]
+        fn writes_to_row_outside_of_partial_sheet() {
+            let mut smartsheet = {
+                let _mock_sheet = mockito::mock("GET", "/sheets/11?include=objectValue&level=2&rowIds=32")
+                    .with_body(json!({
+                            "id": 11,
+                            "name": "my_sheet",
+                            "version": 5,
+                            "modifiedAt": "2018-10-01T10:00:00Z",
+                            "columns": [
+                                {
+                                    "id": 21,
+                                    "title": "my_column"
+                                }
+                            ],
+                            "rows": [
+                                {
+                                    "id": 32,
+                                    "modifiedAt": "2018-10-01T10:00:00Z",
+                                    "cells": []
+                                }
+                            ]
+                        }).to_string())
+                    .create();
+                let options = SheetOptions::new()
+                    .with_row_ids(vec![RowId::from(32)]);
+                Smartsheet::fetch_by_id_with_options(&Client::new_mocked(), SheetId::from(11), &options)
+                    .unwrap()
+                    .with_conditional_writes()
+            };
+            let mock_version = mock_version(6);
+            let mock_modified_rows = mock_modified_rows(json!([create_modified_row(31)]));
+            let mock_push = mock_push(7);
+
+            let result = smartsheet.push_cell_value(&ColumnId::from(21), &RowId::from(31), "new_data");
+
+            mock_version.assert();
+            mock_modified_rows.assert();
+            mock_push.assert();
+            assert_eq!(Ok(()), result);
+        }
+
+        #[test]
+        fn rejects_adding_row_next_to_modified_row() {
+            let mut smartsheet = create_versioned_smartsheet()
+                .with_conditional_writes();
+            let mock_version = mock_version(6);
+            let mock_modified_rows = mock_modified_rows(json!([create_modified_row(31)]));
+            let mock_add = mockito::mock("POST", "/sheets/11/rows")
+                .expect(0)
+                .create();
+
+            let result = smartsheet.add_rows(vec![NewRow::new(RowLocation::Below(RowId::from(31)), vec![])]);
+
+            mock_version.assert();
+            mock_modified_rows.assert();
+            mock_add.assert();
+            match result {
+                Err(Error::Conflict { version: 6, ref rows }) => assert_eq!(RowId::from(31), rows[0].get_row_id()),
+                _ => panic!("Invalid result: '{:?}'", result),
+            }
+        }
+
+        #[test]
+        fn advances_version_after_adding_rows() {
+            let mut smartsheet = create_versioned_smartsheet()
+                .with_conditional_writes();
+            let mock_add = mockito::mock("POST", "/sheets/11/rows")
+                .with_body(json!({
+                        "version": 6,
+                        "result": [
+                            {
+                                "id": 33,
+                                "cells": []
+                            }
+                        ]
+                    }).to_string())
+                .create();
+
+            let result = smartsheet.add_rows(vec![NewRow::new(RowLocation::ToBottom, vec![])]);
+
+            mock_add.assert();
+            assert_eq!(Ok(vec![RowId::from(33)]), result);
+            assert_eq!(Some(6), smartsheet.get_version());
+        }
+    }
+
+    mod get_column_id {
+        use super::*;
+
+        mod when_column_exists {
+            use super::*;
+
+            #[test]
+            fn then_returns_id() {
+                let smartsheet = create_smartsheet();
+
+                let result = smartsheet.get_column_id("my_column");
+
+                let expected = ColumnId::from(21);
+                let actual = result.unwrap();
+                assert_eq!(expected, actual);
+            }
+        }
+
+        mod when_column_does_not_exist {
+            use super::*;
 
             #[test]
             fn then_returns_none() {
                 let smartsheet = create_smartsheet();
-                let column_id = &ColumnId::from(21);
-                let searched = &CellValue::from("nonexistent");
 
-                let result = smartsheet.find_row_id(|row| row.get_cell_value(column_id) == Some(searched));
+                let result = smartsheet.get_column_id("nonexistent");
+
+                assert!(result.is_none());
+            }
+        }
+    }
+
+    mod get_column {
+        use super::*;
+        use ColumnType;
+
+        #[test]
+        fn returns_column_schema() {
+            let smartsheet = create_smartsheet();
+
+            let result = smartsheet.get_column(&ColumnId::from(22));
+
+            let actual = result.unwrap();
+            assert_eq!("other_column", actual.get_title());
+            assert_eq!(&ColumnType::Picklist, actual.get_type());
+            assert_eq!(1, actual.get_index());
+            assert!(!actual.is_primary());
+            assert_eq!(&["option_1".to_string(), "option_2".to_string()], actual.get_options());
+            assert!(actual.has_validation());
+            assert_eq!(Some(150), actual.get_width());
+            assert!(actual.is_locked());
+            assert!(actual.accepts_option("option_1"));
+            assert!(!actual.accepts_option("free text"));
+        }
+
+        #[test]
+        fn defaults_missing_attributes() {
+            let smartsheet = create_smartsheet();
+
+            let result = smartsheet.get_column_by_title("my_column");
+
+            let actual = result.unwrap();
+            assert_eq!(&ColumnType::TextNumber, actual.get_type());
+            assert!(actual.get_options().is_empty());
+            assert!(!actual.has_validation());
+            assert!(actual.accepts_option("free text"));
+        }
+    }
+
+    fn mock_columns(columns: Value) -> Mock {
+        mockito::mock("GET", "/sheets/11/columns?page=1&pageSize=100")
+            .match_header("authorization", "Bearer TEST_TOKEN")
+            .with_body(json!({
+                    "data": columns
+                }).to_string())
+            .create()
+    }
+
+    mod add_columns {
+        use super::*;
+        use ColumnType;
+
+        #[test]
+        fn refreshes_columns() {
+            let mut smartsheet = create_smartsheet();
+            let mock_add = mockito::mock("POST", "/sheets/11/columns")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .match_header("content-type", "application/json")
+                .match_body(Matcher::Json(json!([
+                    {
+                        "title": "new_column",
+                        "type": "PICKLIST",
+                        "index": 1,
+                        "options": ["option_1"],
+                        "validation": true
+                    }
+                ])))
+                .with_body(json!({
+                        "result": [
+                            {
+                                "id": 23,
+                                "title": "new_column",
+                                "type": "PICKLIST",
+                                "index": 1
+                            }
+                        ]
+                    }).to_string())
+                .create();
+            let mock_columns = mock_columns(json!([
+                    {
+                        "id": 21,
+                        "title": "my_column",
+                        "index": 0
+                    },
+                    {
+                        "id": 23,
+                        "title": "new_column",
+                        "type": "PICKLIST",
+                        "index": 1
+                    },
+                    {
+                        "id": 22,
+                        "title": "other_column",
+                        "index": 2
+                    }
+                ]));
+            let column = NewColumn::new("new_column", ColumnType::Picklist)
+                .with_options(vec!["option_1".to_string()])
+                .with_validation(true);
+
+            let result = smartsheet.add_columns(1, vec![column]);
+
+            mock_add.assert();
+            mock_columns.assert();
+            assert_eq!(vec![ColumnId::from(23)], result.unwrap());
+            assert_eq!(Some(ColumnId::from(23)), smartsheet.get_column_id("new_column"));
+            assert_eq!(2, smartsheet.get_column(&ColumnId::from(22)).unwrap().get_index());
+        }
+    }
+
+    mod update_column {
+        use super::*;
+
+        #[test]
+        fn refreshes_columns() {
+            let mut smartsheet = create_smartsheet();
+            let mock_update = mockito::mock("PUT", "/sheets/11/columns/21")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .match_header("content-type", "application/json")
+                .match_body(Matcher::Json(json!({
+                    "title": "renamed_column",
+                    "index": 1
+                })))
+                .with_body(json!({
+                        "result": {
+                            "id": 21,
+                            "title": "renamed_column",
+                            "index": 1
+                        }
+                    }).to_string())
+                .create();
+            let mock_columns = mock_columns(json!([
+                    {
+                        "id": 22,
+                        "title": "other_column",
+                        "index": 0
+                    },
+                    {
+                        "id": 21,
+                        "title": "renamed_column",
+                        "index": 1
+                    }
+                ]));
+            let update = ColumnUpdate::new()
+                .with_title("renamed_column")
+                .with_index(1);
+
+            let result = smartsheet.update_column(&ColumnId::from(21), update);
+
+            mock_update.assert();
+            mock_columns.assert();
+            assert!(result.is_ok());
+            assert_eq!(Some(ColumnId::from(21)), smartsheet.get_column_id("renamed_column"));
+            assert!(smartsheet.get_column_id("my_column").is_none());
+        }
+    }
+
+    mod delete_column {
+        use super::*;
+
+        #[test]
+        fn removes_column_and_its_cells() {
+            let mut smartsheet = create_smartsheet();
+            let mock_delete = mockito::mock("DELETE", "/sheets/11/columns/22")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "message": "SUCCESS",
+                        "resultCode": 0
+                    }).to_string())
+                .create();
+            let mock_columns = mock_columns(json!([
+                    {
+                        "id": 21,
+                        "title": "my_column",
+                        "index": 0
+                    }
+                ]));
+
+            let result = smartsheet.delete_column(&ColumnId::from(22));
+
+            mock_delete.assert();
+            mock_columns.assert();
+            assert!(result.is_ok());
+            assert!(smartsheet.get_column_id("other_column").is_none());
+            assert!(smartsheet.get_cell_value(&ColumnId::from(22), &RowId::from(31)).is_none());
+            assert_cell_value("data_21_31", &smartsheet, 21, 31);
+        }
+    }
+
+    mod find_row_id {
+        use super::*;
+
+        mod when_row_is_found {
+            use super::*;
+
+            #[test]
+            fn then_returns_id() {
+                let smartsheet = create_smartsheet();
+                let column_id = &ColumnId::from(21);
+                let searched = &CellValue::from("data_21_31");
+
+                let result = smartsheet.find_row_id(|row| row.get_cell_value(column_id) == Some(searched));
+
+                let expected = RowId::from(31);
+                let actual = result.unwrap();
+                assert_eq!(expected, actual);
+            }
+        }
+
+        mod when_row_is_not_found {
+            use super::*;
+
+            #[test]
+            fn then_returns_none() {
+                let smartsheet = create_smartsheet();
+                let column_id = &ColumnId::from(21);
+                let searched = &CellValue::from("nonexistent");
+
+                let result = smartsheet.find_row_id(|row| row.get_cell_value(column_id) == Some(searched));
+
+                assert!(result.is_none());
+            }
+        }
+    }
+
+    mod push_cell_value {
+        use super::*;
+
+        #[test]
+        fn updates_sheet() {
+            let mut smartsheet = create_smartsheet();
+            let mockito = mockito::mock("PUT", "/sheets/11/rows")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .match_header("content-type", "application/json")
+                .match_body(Matcher::Json(json!({
+                    "id":31,
+                    "cells": [
+                        {
+                            "columnId": 21,
+                            "value": "new_data"
+                        }
+                    ]
+                })))
+                .with_body(json!({
+                       "result": [
+                            {
+                                "id": 31,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "new_data"
+                                    },
+                                    {
+                                        "columnId": 22,
+                                        "value": "data_22_31"
+                                    }
+                                ]
+                            }
+                       ]
+                    }).to_string())
+                .create();
+            let column_id = &ColumnId::from(21);
+            let row_id = &RowId::from(31);
+            let cell_value = CellValue::from("new_data");
+
+            let result = smartsheet.push_cell_value(column_id, row_id, cell_value);
+
+            mockito.assert();
+            assert!(result.is_ok());
+            assert_cell_value("new_data",   &smartsheet, 21, 31);
+            assert_cell_value("data_22_31", &smartsheet, 22, 31);
+            assert_cell_value("data_21_32", &smartsheet, 21, 32);
+        }
+    }
+
+    mod row_metadata {
+        use super::*;
+        use Contact;
+
+        fn create_locked_smartsheet() -> Smartsheet {
+            create_smartsheet_with(json!({
+                    "id": 11,
+                    "name": "my_sheet",
+                    "columns": [
+                        {
+                            "id": 21,
+                            "title": "my_column"
+                        }
+                    ],
+                    "rows": [
+                        {
+                            "id": 31,
+                            "rowNumber": 1,
+                            "expanded": true,
+                            "locked": true,
+                            "lockedForUser": true,
+                            "createdAt": "2018-10-01T10:00:00Z",
+                            "modifiedAt": "2018-10-02T12:30:00Z",
+                            "createdBy": {
+                                "email": "jane@example.com",
+                                "name": "Jane Doe"
+                            },
+                            "modifiedBy": {
+                                "email": "john@example.com",
+                                "name": "John Doe"
+                            },
+                            "version": 7,
+                            "format": ",,1,,,,,,,,,,,,,,",
+                            "cells": []
+                        },
+                        {
+                            "id": 32,
+                            "rowNumber": 2,
+                            "parentId": 31,
+                            "cells": []
+                        },
+                        {
+                            "id": 33,
+                            "rowNumber": 3,
+                            "parentId": 31,
+                            "siblingId": 32,
+                            "locked": true,
+                            "cells": []
+                        }
+                    ]
+                }))
+        }
+
+        #[test]
+        fn reads_row_metadata() {
+            let smartsheet = create_locked_smartsheet();
+
+            let parent = smartsheet.get_row(&RowId::from(31)).unwrap();
+            let child = smartsheet.get_row(&RowId::from(33)).unwrap();
+
+            assert_eq!(Some(1), parent.get_row_number());
+            assert_eq!(Some(true), parent.is_expanded());
+            assert_eq!(Some("2018-10-01T10:00:00Z"), parent.get_created_at());
+            assert_eq!(Some("2018-10-02T12:30:00Z"), parent.get_modified_at());
+            assert_eq!(Some(&Contact::new("jane@example.com").with_name("Jane Doe")), parent.get_created_by());
+            assert_eq!(Some(&Contact::new("john@example.com").with_name("John Doe")), parent.get_modified_by());
+            assert_eq!(Some(7), parent.get_version());
+            assert_eq!(Some(",,1,,,,,,,,,,,,,,"), parent.get_format());
+            assert_eq!(None, parent.get_parent_id());
+            assert_eq!(Some(3), child.get_row_number());
+            assert_eq!(Some(RowId::from(31)), child.get_parent_id());
+            assert_eq!(Some(RowId::from(32)), child.get_sibling_id());
+        }
+
+        #[test]
+        fn reports_rows_locked_for_user() {
+            let smartsheet = create_locked_smartsheet();
+
+            assert!(smartsheet.is_row_locked(&RowId::from(31)));
+            assert!(!smartsheet.is_row_locked(&RowId::from(32)));
+            assert!(!smartsheet.is_row_locked(&RowId::from(33)));
+            assert!(smartsheet.get_row(&RowId::from(33)).unwrap().is_locked());
+        }
+    }
+
+    mod row_tree {
+        use super::*;
+
+        #[test]
+        fn returns_children_in_sheet_order() {
+            let smartsheet = create_plan_smartsheet();
+
+            assert_eq!(vec![31, 32], to_ids(&smartsheet.get_children(&RowId::from(35))));
+            assert!(smartsheet.get_children(&RowId::from(33)).is_empty());
+        }
+
+        #[test]
+        fn returns_ancestors_and_depth() {
+            let smartsheet = create_plan_smartsheet();
+
+            assert_eq!(vec![31, 35], to_ids(&smartsheet.get_ancestors(&RowId::from(34))));
+            assert_eq!(Some(2), smartsheet.get_depth(&RowId::from(34)));
+            assert_eq!(Some(0), smartsheet.get_depth(&RowId::from(33)));
+            assert_eq!(None, smartsheet.get_depth(&RowId::from(99)));
+        }
+
+        #[test]
+        fn walks_depth_first() {
+            let smartsheet = create_plan_smartsheet();
+
+            let actual = smartsheet.walk_depth_first();
+
+            assert_eq!(vec![(0, 35), (1, 31), (2, 34), (1, 32), (0, 33)], to_depths_and_ids(&actual));
+        }
+
+        #[test]
+        fn returns_subtree() {
+            let smartsheet = create_plan_smartsheet();
+
+            let actual = smartsheet.get_subtree(&RowId::from(31));
+
+            assert_eq!(vec![(0, 31), (1, 34)], to_depths_and_ids(&actual));
+            assert!(smartsheet.get_subtree(&RowId::from(99)).is_empty());
+        }
+    }
+
+    mod row_order {
+        use super::*;
+        use RowLocation;
+
+        fn get_row_ids(smartsheet: &Smartsheet) -> Vec<u64> {
+            to_ids(&smartsheet.get_rows_iter().collect::<Vec<_>>())
+        }
+
+        #[test]
+        fn keeps_sheet_order() {
+            let smartsheet = create_plan_smartsheet();
+
+            assert_eq!(vec![35, 31, 34, 32, 33], get_row_ids(&smartsheet));
+        }
+
+        #[test]
+        fn finds_first_row_in_sheet_order() {
+            let smartsheet = create_plan_smartsheet();
+
+            let result = smartsheet.find_row_id(|row| row.get_parent_id().is_none());
+
+            assert_eq!(Some(RowId::from(35)), result);
+        }
+
+        #[test]
+        fn inserts_added_row_after_sibling() {
+            let mut smartsheet = create_plan_smartsheet();
+            let mockito = mockito::mock("POST", "/sheets/11/rows")
+                .with_body(json!({
+                       "result": [
+                            {
+                                "id": 36,
+                                "rowNumber": 4,
+                                "parentId": 35,
+                                "siblingId": 31
+                            }
+                       ]
+                    }).to_string())
+                .create();
+
+            let result = smartsheet.add_rows(vec![NewRow::new(RowLocation::Below(RowId::from(31)), vec![])]);
+
+            mockito.assert();
+            assert!(result.is_ok());
+            assert_eq!(vec![35, 31, 34, 36, 32, 33], get_row_ids(&smartsheet));
+            assert_eq!(Some(5), smartsheet.get_row(&RowId::from(32)).unwrap().get_row_number());
+        }
+
+        #[test]
+        fn inserts_added_rows_in_order() {
+            let mut smartsheet = create_plan_smartsheet();
+            let mockito = mockito::mock("POST", "/sheets/11/rows")
+                .with_body(json!({
+                       "result": [
+                            {
+                                "id": 36,
+                                "rowNumber": 4,
+                                "parentId": 35,
+                                "siblingId": 31
+                            },
+                            {
+                                "id": 37,
+                                "rowNumber": 5,
+                                "parentId": 35,
+                                "siblingId": 36
+                            }
+                       ]
+                    }).to_string())
+                .create();
+            let new_rows = vec![
+                NewRow::new(RowLocation::Below(RowId::from(31)), vec![]),
+                NewRow::new(RowLocation::Below(RowId::from(31)), vec![]),
+            ];
+
+            let result = smartsheet.add_rows(new_rows);
+
+            mockito.assert();
+            assert!(result.is_ok());
+            assert_eq!(vec![35, 31, 34, 36, 37, 32, 33], get_row_ids(&smartsheet));
+            assert_eq!(Some(7), smartsheet.get_row(&RowId::from(33)).unwrap().get_row_number());
+        }
+
+        #[test]
+        fn moves_row_with_children() {
+            let mut smartsheet = create_plan_smartsheet();
+            let mockito = mockito::mock("PUT", "/sheets/11/rows")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .match_header("content-type", "application/json")
+                .match_body(Matcher::Json(json!([
+                    {
+                        "id": 31,
+                        "toTop": true
+                    }
+                ])))
+                .with_body(json!({
+                       "result": [
+                            {
+                                "id": 31,
+                                "rowNumber": 1
+                            }
+                       ]
+                    }).to_string())
+                .create();
+
+            let result = smartsheet.move_row(&RowId::from(31), RowLocation::ToTop);
+
+            mockito.assert();
+            assert!(result.is_ok());
+            assert_eq!(vec![31, 34, 35, 32, 33], get_row_ids(&smartsheet));
+            assert_eq!(Some(1), smartsheet.get_depth(&RowId::from(34)));
+        }
+
+        #[test]
+        fn removes_deleted_row_with_children() {
+            let mut smartsheet = create_plan_smartsheet();
+            let mockito = mockito::mock("DELETE", "/sheets/11/rows?ids=31&ignoreRowsNotFound=false")
+                .with_body(json!({
+                       "result": [31, 34]
+                    }).to_string())
+                .create();
+
+            let result = smartsheet.delete_rows(&[RowId::from(31)], false);
+
+            mockito.assert();
+            assert!(result.is_ok());
+            assert_eq!(vec![35, 32, 33], get_row_ids(&smartsheet));
+            assert_eq!(Some(2), smartsheet.get_row(&RowId::from(32)).unwrap().get_row_number());
+        }
+    }
+
+    mod typed_values {
+        use super::*;
+        use {Contact, DurationValue, Predecessor};
+
+        fn create_typed_smartsheet() -> Smartsheet {
+            create_smartsheet_with(json!({
+                    "id": 11,
+                    "name": "my_sheet",
+                    "columns": [
+                        { "id": 21, "title": "date", "type": "DATE" },
+                        { "id": 22, "title": "contact", "type": "CONTACT_LIST" },
+                        { "id": 23, "title": "contacts", "type": "MULTI_CONTACT_LIST" },
+                        { "id": 24, "title": "picklist", "type": "MULTI_PICKLIST" },
+                        { "id": 25, "title": "duration", "type": "DURATION" },
+                        { "id": 26, "title": "predecessors", "type": "PREDECESSOR" }
+                    ],
+                    "rows": [
+                        {
+                            "id": 31,
+                            "cells": [
+                                {
+                                    "columnId": 21,
+                                    "value": "2018-12-01"
+                                },
+                                {
+                                    "columnId": 22,
+                                    "value": "john@example.com",
+                                    "objectValue": {
+                                        "objectType": "CONTACT",
+                                        "email": "john@example.com",
+                                        "name": "John"
+                                    }
+                                },
+                                {
+                                    "columnId": 23,
+                                    "value": "John, jane@example.com",
+                                    "objectValue": {
+                                        "objectType": "MULTI_CONTACT",
+                                        "values": [
+                                            {
+                                                "objectType": "CONTACT",
+                                                "email": "john@example.com",
+                                                "name": "John"
+                                            },
+                                            {
+                                                "objectType": "CONTACT",
+                                                "email": "jane@example.com"
+                                            }
+                                        ]
+                                    }
+                                },
+                                {
+                                    "columnId": 24,
+                                    "value": "a, b",
+                                    "objectValue": {
+                                        "objectType": "MULTI_PICKLIST",
+                                        "values": ["a", "b"]
+                                    }
+                                },
+                                {
+                                    "columnId": 25,
+                                    "value": "2d",
+                                    "objectValue": {
+                                        "objectType": "DURATION",
+                                        "days": 2
+                                    }
+                                },
+                                {
+                                    "columnId": 26,
+                                    "value": "2FS",
+                                    "objectValue": {
+                                        "objectType": "PREDECESSOR_LIST",
+                                        "predecessors": [
+                                            {
+                                                "rowId": 32,
+                                                "rowNumber": 2,
+                                                "type": "FS"
+                                            }
+                                        ]
+                                    }
+                                }
+                            ]
+                        },
+                        {
+                            "id": 32,
+                            "cells": [
+                                {
+                                    "columnId": 22,
+                                    "value": "Free text"
+                                },
+                                {
+                                    "columnId": 25,
+                                    "value": "unknown object",
+                                    "objectValue": {
+                                        "objectType": "UNKNOWN"
+                                    }
+                                }
+                            ]
+                        }
+                    ]
+                }))
+        }
+
+        fn get_value(smartsheet: &Smartsheet, column_id: u64, row_id: u64) -> &CellValue {
+            smartsheet.get_cell_value(&ColumnId::from(column_id), &RowId::from(row_id))
+                .unwrap()
+        }
+
+        #[test]
+        fn interprets_values_by_column_type() {
+            let smartsheet = create_typed_smartsheet();
+
+            assert_eq!(&CellValue::Date("2018-12-01".to_string()), get_value(&smartsheet, 21, 31));
+            assert_eq!(&CellValue::Contact(Contact::new("john@example.com").with_name("John")),
+                get_value(&smartsheet, 22, 31));
+            assert_eq!(&CellValue::MultiContact(vec![
+                    Contact::new("john@example.com").with_name("John"),
+                    Contact::new("jane@example.com"),
+                ]), get_value(&smartsheet, 23, 31));
+            assert_eq!(&CellValue::MultiPicklist(vec!["a".to_string(), "b".to_string()]),
+                get_value(&smartsheet, 24, 31));
+            assert_eq!(&CellValue::Duration(DurationValue::new().with_days(2.0)), get_value(&smartsheet, 25, 31));
+            match *get_value(&smartsheet, 26, 31) {
+                CellValue::Predecessors(ref predecessors) => {
+                    assert_eq!(1, predecessors.len());
+                    assert_eq!(RowId::from(32), predecessors[0].get_row_id());
+                    assert_eq!(Some(2), predecessors[0].get_row_number());
+                    assert_eq!("FS", predecessors[0].get_type());
+                },
+                ref other => panic!("Invalid value: '{:?}'", other),
+            }
+        }
+
+        #[test]
+        fn keeps_values_without_known_object_value() {
+            let smartsheet = create_typed_smartsheet();
+
+            assert_eq!(&CellValue::from("Free text"), get_value(&smartsheet, 22, 32));
+            assert_eq!(&CellValue::from("unknown object"), get_value(&smartsheet, 25, 32));
+        }
+
+        #[test]
+        fn pushes_object_values() {
+            let mut smartsheet = create_typed_smartsheet();
+            let mockito = mockito::mock("PUT", "/sheets/11/rows")
+                .match_body(Matcher::Json(json!([
+                    {
+                        "id": 32,
+                        "cells": [
+                            {
+                                "columnId": 24,
+                                "objectValue": {
+                                    "objectType": "MULTI_PICKLIST",
+                                    "values": ["c"]
+                                }
+                            },
+                            {
+                                "columnId": 26,
+                                "objectValue": {
+                                    "objectType": "PREDECESSOR_LIST",
+                                    "predecessors": [
+                                        {
+                                            "rowId": 31,
+                                            "type": "SS",
+                                            "lag": {
+                                                "negative": false,
+                                                "elapsed": false,
+                                                "days": 1.0
+                                            }
+                                        }
+                                    ]
+                                }
+                            },
+                            {
+                                "columnId": 22,
+                                "objectValue": {
+                                    "objectType": "CONTACT",
+                                    "email": "jane@example.com",
+                                    "name": "Jane"
+                                }
+                            },
+                            {
+                                "columnId": 21,
+                                "value": "2018-12-24"
+                            }
+                        ]
+                    }
+                ])))
+                .with_body(json!({
+                        "result": []
+                    }).to_string())
+                .create();
+            let predecessor = Predecessor::new(RowId::from(31), "SS")
+                .with_lag(DurationValue::new().with_days(1.0));
+            let rows = vec![
+                Row::new_with_cells(RowId::from(32), vec![
+                    Cell::new(ColumnId::from(24), CellValue::MultiPicklist(vec!["c".to_string()])),
+                    Cell::new(ColumnId::from(26), CellValue::Predecessors(vec![predecessor])),
+                    Cell::new(ColumnId::from(22), CellValue::Contact(Contact::new("jane@example.com").with_name("Jane"))),
+                    Cell::new(ColumnId::from(21), CellValue::Date("2018-12-24".to_string())),
+                ]),
+            ];
+
+            let result = smartsheet.push_rows(rows);
+
+            mockito.assert();
+            assert!(result.is_ok());
+        }
+    }
+
+    mod push_cell_formula {
+        use super::*;
+
+        #[test]
+        fn updates_sheet_with_computed_value() {
+            let mut smartsheet = create_smartsheet();
+            let mockito = mockito::mock("PUT", "/sheets/11/rows")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .match_header("content-type", "application/json")
+                .match_body(Matcher::Json(json!({
+                    "id": 32,
+                    "cells": [
+                        {
+                            "columnId": 22,
+                            "formula": "=COUNT([my_column]1:[my_column]2)"
+                        }
+                    ]
+                })))
+                .with_body(json!({
+                       "result": [
+                            {
+                                "id": 32,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "data_21_32"
+                                    },
+                                    {
+                                        "columnId": 22,
+                                        "value": 2,
+                                        "formula": "=COUNT([my_column]1:[my_column]2)"
+                                    }
+                                ]
+                            }
+                       ]
+                    }).to_string())
+                .create();
+            let column_id = &ColumnId::from(22);
+            let row_id = &RowId::from(32);
+
+            let result = smartsheet.push_cell_formula(column_id, row_id, "=COUNT([my_column]1:[my_column]2)");
+
+            mockito.assert();
+            assert!(result.is_ok());
+            let cell = smartsheet.get_cell(column_id, row_id).unwrap();
+            assert_eq!(&CellValue::Number(2.0), cell.get_value());
+            assert_eq!(Some("=COUNT([my_column]1:[my_column]2)"), cell.get_formula());
+        }
+    }
+
+    mod push_cell_link {
+        use super::*;
+        use CellLinkStatus;
+
+        #[test]
+        fn links_cell_to_source_cell() {
+            let mut smartsheet = create_smartsheet();
+            let mockito = mockito::mock("PUT", "/sheets/11/rows")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .match_header("content-type", "application/json")
+                .match_body(Matcher::Json(json!({
+                    "id": 32,
+                    "cells": [
+                        {
+                            "columnId": 22,
+                            "value": null,
+                            "linkInFromCell": {
+                                "sheetId": 12,
+                                "rowId": 41,
+                                "columnId": 51
+                            }
+                        }
+                    ]
+                })))
+                .with_body(json!({
+                       "result": [
+                            {
+                                "id": 32,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "data_21_32"
+                                    },
+                                    {
+                                        "columnId": 22,
+                                        "value": "linked_data",
+                                        "linkInFromCell": {
+                                            "status": "OK",
+                                            "sheetId": 12,
+                                            "rowId": 41,
+                                            "columnId": 51,
+                                            "sheetName": "source_sheet"
+                                        }
+                                    }
+                                ]
+                            }
+                       ]
+                    }).to_string())
+                .create();
+            let column_id = &ColumnId::from(22);
+            let row_id = &RowId::from(32);
+            let source = CellLink::new(SheetId::from(12), RowId::from(41), ColumnId::from(51));
+
+            let result = smartsheet.push_cell_link(column_id, row_id, source);
+
+            mockito.assert();
+            assert!(result.is_ok());
+            assert_cell_value("linked_data", &smartsheet, 22, 32);
+            let link = smartsheet.get_cell(column_id, row_id).unwrap()
+                .get_link_in_from_cell()
+                .unwrap();
+            assert_eq!(Some(&CellLinkStatus::Ok), link.get_status());
+            assert_eq!(SheetId::from(12), link.get_sheet_id());
+            assert_eq!(Some("source_sheet"), link.get_sheet_name());
+        }
+    }
+
+    mod remove_cell_link {
+        use super::*;
+
+        fn create_linked_smartsheet() -> Smartsheet {
+            create_smartsheet_with(json!({
+                    "id": 11,
+                    "name": "my_sheet",
+                    "columns": [
+                        {
+                            "id": 21,
+                            "title": "my_column"
+                        },
+                        {
+                            "id": 22,
+                            "title": "other_column"
+                        }
+                    ],
+                    "rows": [
+                        {
+                            "id": 31,
+                            "cells": [
+                                {
+                                    "columnId": 21,
+                                    "value": "data_21_31"
+                                },
+                                {
+                                    "columnId": 22,
+                                    "value": "data_22_31",
+                                    "linkInFromCell": {
+                                        "status": "OK",
+                                        "sheetId": 12,
+                                        "rowId": 41,
+                                        "columnId": 51
+                                    }
+                                }
+                            ]
+                        }
+                    ]
+                }))
+        }
+
+        #[test]
+        fn keeps_linked_value() {
+            let mut smartsheet = create_linked_smartsheet();
+            let mockito = mockito::mock("PUT", "/sheets/11/rows")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .match_header("content-type", "application/json")
+                .match_body(Matcher::Json(json!({
+                    "id": 31,
+                    "cells": [
+                        {
+                            "columnId": 22,
+                            "value": "data_22_31"
+                        }
+                    ]
+                })))
+                .with_body(json!({
+                       "result": [
+                            {
+                                "id": 31,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "data_21_31"
+                                    },
+                                    {
+                                        "columnId": 22,
+                                        "value": "data_22_31"
+                                    }
+                                ]
+                            }
+                       ]
+                    }).to_string())
+                .create();
+            let column_id = &ColumnId::from(22);
+            let row_id = &RowId::from(31);
+
+            let result = smartsheet.remove_cell_link(column_id, row_id);
+
+            mockito.assert();
+            assert!(result.is_ok());
+            let cell = smartsheet.get_cell(column_id, row_id).unwrap();
+            assert_eq!(None, cell.get_link_in_from_cell());
+        }
+
+        #[test]
+        fn ignores_cell_without_link() {
+            let mut smartsheet = create_linked_smartsheet();
+            let mockito = mockito::mock("PUT", "/sheets/11/rows")
+                .expect(0)
+                .create();
+
+            let result = smartsheet.remove_cell_link(&ColumnId::from(21), &RowId::from(31));
+
+            mockito.assert();
+            assert_eq!(Ok(()), result);
+            assert_cell_value("data_21_31", &smartsheet, 21, 31);
+        }
+    }
+
+    mod cell_metadata {
+        use super::*;
+        use {CellLinkStatus, Hyperlink};
+
+        fn create_formula_smartsheet() -> Smartsheet {
+            create_smartsheet_with(json!({
+                    "id": 11,
+                    "name": "my_sheet",
+                    "columns": [
+                        { "id": 21, "title": "cost", "type": "TEXT_NUMBER" },
+                        { "id": 22, "title": "link", "type": "TEXT_NUMBER" }
+                    ],
+                    "rows": [
+                        {
+                            "id": 31,
+                            "cells": [
+                                {
+                                    "columnId": 21,
+                                    "value": 1500.5,
+                                    "displayValue": "$1,500.50",
+                                    "formula": "=SUM([cost]2:[cost]3)",
+                                    "format": ",,,,,,,,,,,13,2,1,2,,",
+                                    "conditionalFormat": ",,1,,,,,,,22,,,,,,,"
+                                },
+                                {
+                                    "columnId": 22,
+                                    "value": "Docs",
+                                    "hyperlink": {
+                                        "url": "https://example.com"
+                                    },
+                                    "linksOutToCells": [
+                                        {
+                                            "status": "OK",
+                                            "sheetId": 12,
+                                            "rowId": 41,
+                                            "columnId": 51,
+                                            "sheetName": "other_sheet"
+                                        }
+                                    ],
+                                    "image": {
+                                        "id": "image_id",
+                                        "height": 16,
+                                        "width": 32,
+                                        "altText": "logo"
+                                    }
+                                }
+                            ]
+                        }
+                    ]
+                }))
+        }
+
+        #[test]
+        fn reads_cell_metadata() {
+            let smartsheet = create_formula_smartsheet();
+
+            let formula_cell = smartsheet.get_cell(&ColumnId::from(21), &RowId::from(31)).unwrap();
+            let link_cell = smartsheet.get_cell(&ColumnId::from(22), &RowId::from(31)).unwrap();
+
+            assert_eq!(&CellValue::Number(1500.5), formula_cell.get_value());
+            assert_eq!(Some("$1,500.50"), formula_cell.get_display_value());
+            assert_eq!(Some("=SUM([cost]2:[cost]3)"), formula_cell.get_formula());
+            assert_eq!(Some(",,,,,,,,,,,13,2,1,2,,"), formula_cell.get_format());
+            assert_eq!(Some(",,1,,,,,,,22,,,,,,,"), formula_cell.get_conditional_format());
+            assert_eq!(Some(&Hyperlink::new_url("https://example.com")), link_cell.get_hyperlink());
+            let link_out = &link_cell.get_links_out_to_cells()[0];
+            assert_eq!(Some(&CellLinkStatus::Ok), link_out.get_status());
+            assert_eq!(SheetId::from(12), link_out.get_sheet_id());
+            assert_eq!(Some("other_sheet"), link_out.get_sheet_name());
+            let image = link_cell.get_image().unwrap();
+            assert_eq!("image_id", image.get_id());
+            assert_eq!(Some("logo"), image.get_alt_text());
+        }
+
+        #[test]
+        fn rejects_overwriting_formula_with_value() {
+            let mut smartsheet = create_formula_smartsheet();
+
+            let result = smartsheet.push_cell_value(&ColumnId::from(21), &RowId::from(31), 100.0);
+
+            let expected = Error::FormulaOverwrite {
+                column_id: ColumnId::from(21),
+                row_id: RowId::from(31),
+            };
+            assert_eq!(expected, result.unwrap_err());
+            assert_eq!(Some("=SUM([cost]2:[cost]3)"),
+                smartsheet.get_cell(&ColumnId::from(21), &RowId::from(31)).unwrap().get_formula());
+        }
+
+        #[test]
+        fn rejects_overwriting_formula_with_link() {
+            let mut smartsheet = create_formula_smartsheet();
+            let source = CellLink::new(SheetId::from(12), RowId::from(41), ColumnId::from(51));
+
+            let result = smartsheet.push_cell_link(&ColumnId::from(21), &RowId::from(31), source);
+
+            let expected = Error::FormulaOverwrite {
+                column_id: ColumnId::from(21),
+                row_id: RowId::from(31),
+            };
+            assert_eq!(expected, result.unwrap_err());
+            assert_eq!(Some("=SUM([cost]2:[cost]3)"),
+                smartsheet.get_cell(&ColumnId::from(21), &RowId::from(31)).unwrap().get_formula());
+        }
+
+        #[test]
+        fn overwrites_formula_when_allowed() {
+            let mut smartsheet = create_formula_smartsheet();
+            let mockito = mockito::mock("PUT", "/sheets/11/rows")
+                .match_body(Matcher::Json(json!([
+                    {
+                        "id": 31,
+                        "cells": [
+                            {
+                                "columnId": 21,
+                                "value": 100.0
+                            },
+                            {
+                                "columnId": 22,
+                                "value": "Smartsheet",
+                                "hyperlink": {
+                                    "url": "https://smartsheet.com"
+                                }
+                            }
+                        ]
+                    }
+                ])))
+                .with_body(json!({
+                        "result": [
+                            {
+                                "id": 31,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": 100.0
+                                    }
+                                ]
+                            }
+                        ]
+                    }).to_string())
+                .create();
+            let rows = vec![
+                Row::new_with_cells(RowId::from(31), vec![
+                    Cell::new(ColumnId::from(21), CellValue::from(100.0))
+                        .allow_formula_overwrite(),
+                    Cell::new(ColumnId::from(22), CellValue::from("Smartsheet"))
+                        .with_hyperlink(Hyperlink::new_url("https://smartsheet.com")),
+                ]),
+            ];
+
+            let result = smartsheet.push_rows(rows);
+
+            mockito.assert();
+            assert!(result.is_ok());
+            assert_eq!(None, smartsheet.get_cell(&ColumnId::from(21), &RowId::from(31)).unwrap().get_formula());
+        }
+    }
+
+    mod push_rows {
+        use super::*;
+
+        #[test]
+        fn updates_sheet() {
+            let mut smartsheet = create_smartsheet();
+            let mockito = mockito::mock("PUT", "/sheets/11/rows")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .match_header("content-type", "application/json")
+                .match_body(Matcher::Json(json!([
+                    {
+                        "id": 31,
+                        "cells": [
+                            {
+                                "columnId": 21,
+                                "value": "new_data_21_31"
+                            },
+                            {
+                                "columnId": 22,
+                                "value": "new_data_22_31"
+                            }
+                        ]
+                    },
+                    {
+                        "id": 32,
+                        "cells": [
+                            {
+                                "columnId": 22,
+                                "value": "new_data_22_32"
+                            }
+                        ]
+                    }
+                ])))
+                .with_body(json!({
+                       "result": [
+                            {
+                                "id": 31,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "new_data_21_31"
+                                    },
+                                    {
+                                        "columnId": 22,
+                                        "value": "new_data_22_31"
+                                    }
+                                ]
+                            },
+                            {
+                                "id": 32,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "data_21_32"
+                                    },
+                                    {
+                                        "columnId": 22,
+                                        "value": "new_data_22_32"
+                                    }
+                                ]
+                            }
+                       ]
+                    }).to_string())
+                .create();
+            let rows = vec![
+                Row::new_with_cells(RowId::from(31), vec![
+                    Cell::new(ColumnId::from(21), CellValue::from("new_data_21_31")),
+                    Cell::new(ColumnId::from(22), CellValue::from("new_data_22_31")),
+                ]),
+                Row::new(RowId::from(32), Cell::new(ColumnId::from(22), CellValue::from("new_data_22_32"))),
+            ];
+
+            let result = smartsheet.push_rows(rows);
+
+            mockito.assert();
+            assert!(result.is_ok());
+            assert_cell_value("new_data_21_31", &smartsheet, 21, 31);
+            assert_cell_value("new_data_22_31", &smartsheet, 22, 31);
+            assert_cell_value("data_21_32",     &smartsheet, 21, 32);
+            assert_cell_value("new_data_22_32", &smartsheet, 22, 32);
+        }
+
+        #[test]
+        fn splits_large_updates_into_chunks() {
+            let mut smartsheet = create_smartsheet();
+            let mockito = mockito::mock("PUT", "/sheets/11/rows")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "result": []
+                    }).to_string())
+                .expect(3)
+                .create();
+            let rows = (0..MAX_ROWS_PER_REQUEST * 2 + 1)
+                .map(|index| Row::new(RowId::from(index as u64), Cell::new(ColumnId::from(21), CellValue::Empty)))
+                .collect();
+
+            let result = smartsheet.push_rows(rows);
+
+            mockito.assert();
+            assert!(result.is_ok());
+        }
+    }
+
+    mod add_rows {
+        use super::*;
+        use RowLocation;
+
+        #[test]
+        fn inserts_rows_into_sheet() {
+            let mut smartsheet = create_smartsheet();
+            let mockito = mockito::mock("POST", "/sheets/11/rows")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .match_header("content-type", "application/json")
+                .match_body(Matcher::Json(json!([
+                    {
+                        "toBottom": true,
+                        "cells": [
+                            {
+                                "columnId": 21,
+                                "value": "data_21_33"
+                            }
+                        ]
+                    },
+                    {
+                        "siblingId": 31,
+                        "above": true,
+                        "cells": [
+                            {
+                                "columnId": 21,
+                                "value": "data_21_34"
+                            }
+                        ]
+                    }
+                ])))
+                .with_body(json!({
+                       "result": [
+                            {
+                                "id": 33,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "data_21_33"
+                                    }
+                                ]
+                            },
+                            {
+                                "id": 34,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "data_21_34"
+                                    }
+                                ]
+                            }
+                       ]
+                    }).to_string())
+                .create();
+            let rows = vec![
+                NewRow::new(RowLocation::ToBottom, vec![Cell::new(ColumnId::from(21), CellValue::from("data_21_33"))]),
+                NewRow::new(RowLocation::Above(RowId::from(31)), vec![Cell::new(ColumnId::from(21), CellValue::from("data_21_34"))]),
+            ];
+
+            let result = smartsheet.add_rows(rows);
+
+            mockito.assert();
+            assert_eq!(vec![RowId::from(33), RowId::from(34)], result.unwrap());
+            assert_cell_value("data_21_31", &smartsheet, 21, 31);
+            assert_cell_value("data_21_33", &smartsheet, 21, 33);
+            assert_cell_value("data_21_34", &smartsheet, 21, 34);
+        }
+    }
+
+    mod move_rows {
+        use super::*;
+        use TransferOptions;
+
+        fn create_archive_smartsheet() -> Smartsheet {
+            create_smartsheet_with(json!({
+                    "id": 12,
+                    "name": "archive",
+                    "columns": [
+                        {
+                            "id": 61,
+                            "title": "my_column"
+                        }
+                    ],
+                    "rows": [
+                        {
+                            "id": 41,
+                            "rowNumber": 1,
+                            "cells": [
+                                {
+                                    "columnId": 61,
+                                    "value": "archived"
+                                }
+                            ]
+                        }
+                    ]
+                }))
+        }
+
+        #[test]
+        fn updates_both_sheets() {
+            let mut smartsheet = create_smartsheet();
+            let mut archive = create_archive_smartsheet();
+            let mock_move = mockito::mock("POST", "/sheets/11/rows/move?include=attachments%2Cdiscussions")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .match_header("content-type", "application/json")
+                .match_body(Matcher::Json(json!({
+                    "rowIds": [31],
+                    "to": {
+                        "sheetId": 12
+                    }
+                })))
+                .with_body(json!({
+                        "destinationSheetId": 12,
+                        "rowMappings": [
+                            {
+                                "from": 31,
+                                "to": 42
+                            }
+                        ]
+                    }).to_string())
+                .create();
+            let mock_rows = mockito::mock("GET", "/sheets/12?include=objectValue&level=2&rowIds=42")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "id": 12,
+                        "name": "archive",
+                        "columns": [
+                            {
+                                "id": 61,
+                                "title": "my_column"
+                            }
+                        ],
+                        "rows": [
+                            {
+                                "id": 42,
+                                "rowNumber": 2,
+                                "siblingId": 41,
+                                "cells": [
+                                    {
+                                        "columnId": 61,
+                                        "value": "data_21_31"
+                                    }
+                                ]
+                            }
+                        ]
+                    }).to_string())
+                .create();
+            let options = TransferOptions::new()
+                .with_attachments()
+                .with_discussions()
+                .with_children();
+
+            let result = smartsheet.move_rows_into(&[RowId::from(31)], &mut archive, &options);
+
+            mock_move.assert();
+            mock_rows.assert();
+            let mut expected = BTreeMap::new();
+            expected.insert(RowId::from(31), RowId::from(42));
+            assert_eq!(expected, result.unwrap());
+            assert!(smartsheet.get_row(&RowId::from(31)).is_none());
+            assert_cell_value("data_21_32", &smartsheet, 21, 32);
+            assert_cell_value("data_21_31", &archive, 61, 42);
+            assert_eq!(Some(2), archive.get_row(&RowId::from(42)).unwrap().get_row_number());
+        }
+    }
+
+    mod copy_rows {
+        use super::*;
+        use TransferOptions;
+
+        #[test]
+        fn keeps_source_sheet() {
+            let smartsheet = create_smartsheet();
+            let mockito = mockito::mock("POST", "/sheets/11/rows/copy?include=children")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .match_body(Matcher::Json(json!({
+                    "rowIds": [31, 32],
+                    "to": {
+                        "sheetId": 12
+                    }
+                })))
+                .with_body(json!({
+                        "destinationSheetId": 12,
+                        "rowMappings": [
+                            {
+                                "from": 31,
+                                "to": 42
+                            },
+                            {
+                                "from": 32,
+                                "to": 43
+                            }
+                        ]
+                    }).to_string())
+                .create();
+            let options = TransferOptions::new()
+                .with_children();
+
+            let result = smartsheet.copy_rows(&[RowId::from(31), RowId::from(32)], &SheetId::from(12), &options);
 
-                assert!(result.is_none());
-            }
+            mockito.assert();
+            let actual = result.unwrap();
+            assert_eq!(Some(&RowId::from(42)), actual.get(&RowId::from(31)));
+            assert_eq!(Some(&RowId::from(43)), actual.get(&RowId::from(32)));
+            assert_cell_value("data_21_31", &smartsheet, 21, 31);
         }
     }
 
-    mod push_cell_value {
+    mod sort_rows {
         use super::*;
+        use SortCriterion;
+
+        fn create_partial_smartsheet() -> Smartsheet {
+            let _mock_sheet = mockito::mock("GET", "/sheets/11?include=objectValue&level=2&rowIds=32")
+                .with_body(json!({
+                        "id": 11,
+                        "name": "my_sheet",
+                        "columns": [
+                            {
+                                "id": 21,
+                                "title": "my_column"
+                            }
+                        ],
+                        "rows": [
+                            {
+                                "id": 32,
+                                "rowNumber": 2,
+                                "cells": []
+                            }
+                        ]
+                    }).to_string())
+                .create();
+            let client = Client::new_mocked();
+            let options = SheetOptions::new()
+                .with_row_ids(vec![RowId::from(32)]);
+            Smartsheet::fetch_by_id_with_options(&client, SheetId::from(11), &options)
+                .unwrap()
+        }
 
         #[test]
-        fn updates_sheet() {
+        fn reorders_sheet() {
             let mut smartsheet = create_smartsheet();
-            let mockito = mockito::mock("PUT", "/sheets/11/rows")
+            let mockito = mockito::mock("POST", "/sheets/11/sort?include=objectValue&level=2")
                 .match_header("authorization", "Bearer TEST_TOKEN")
                 .match_header("content-type", "application/json")
                 .match_body(Matcher::Json(json!({
-                    "id":31,
-                    "cells": [
+                    "sortCriteria": [
+                        {
+                            "columnId": 22,
+                            "direction": "DESCENDING"
+                        },
                         {
                             "columnId": 21,
-                            "value": "new_data"
+                            "direction": "ASCENDING"
                         }
                     ]
                 })))
                 .with_body(json!({
-                       "result": [
+                        "id": 11,
+                        "name": "my_sheet",
+                        "columns": [
+                            {
+                                "id": 21,
+                                "title": "my_column"
+                            },
+                            {
+                                "id": 22,
+                                "title": "other_column"
+                            }
+                        ],
+                        "rows": [
+                            {
+                                "id": 32,
+                                "rowNumber": 1,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "data_21_32"
+                                    }
+                                ]
+                            },
                             {
                                 "id": 31,
+                                "rowNumber": 2,
                                 "cells": [
                                     {
                                         "columnId": 21,
-                                        "value": "new_data"
+                                        "value": "data_21_31"
                                     },
                                     {
                                         "columnId": 22,
@@ -262,20 +2913,465 @@ mod tests {
                                     }
                                 ]
                             }
-                       ]
+                        ]
                     }).to_string())
                 .create();
-            let column_id = &ColumnId::from(21);
-            let row_id = &RowId::from(31);
-            let cell_value = CellValue::from("new_data");
+            let sort_criteria = [
+                SortCriterion::descending(ColumnId::from(22)),
+                SortCriterion::ascending(ColumnId::from(21)),
+            ];
 
-            let result = smartsheet.push_cell_value(column_id, row_id, cell_value);
+            let result = smartsheet.sort_rows(&sort_criteria);
 
             mockito.assert();
             assert!(result.is_ok());
-            assert_cell_value("new_data",   &smartsheet, 21, 31);
+            let row_ids: Vec<_> = smartsheet.get_rows_iter()
+                .map(Row::get_row_id)
+                .collect();
+            assert_eq!(vec![RowId::from(32), RowId::from(31)], row_ids);
             assert_cell_value("data_22_31", &smartsheet, 22, 31);
+        }
+
+        #[test]
+        fn reloads_sheet_loaded_with_options() {
+            let mut smartsheet = create_partial_smartsheet();
+            let mock_sort = mockito::mock("POST", "/sheets/11/sort?include=objectValue&level=2")
+                .with_body(json!({
+                        "id": 11,
+                        "name": "my_sheet",
+                        "columns": [],
+                        "rows": [
+                            {
+                                "id": 32,
+                                "rowNumber": 1
+                            },
+                            {
+                                "id": 31,
+                                "rowNumber": 2
+                            }
+                        ]
+                    }).to_string())
+                .create();
+            let mock_reload = mockito::mock("GET", "/sheets/11?include=objectValue&level=2&rowIds=32")
+                .with_body(json!({
+                        "id": 11,
+                        "name": "my_sheet",
+                        "columns": [
+                            {
+                                "id": 21,
+                                "title": "my_column"
+                            }
+                        ],
+                        "rows": [
+                            {
+                                "id": 32,
+                                "rowNumber": 1,
+                                "cells": []
+                            }
+                        ]
+                    }).to_string())
+                .create();
+
+            let result = smartsheet.sort_rows(&[SortCriterion::ascending(ColumnId::from(21))]);
+
+            mock_sort.assert();
+            mock_reload.assert();
+            assert!(result.is_ok());
+            assert!(smartsheet.get_row(&RowId::from(31)).is_none());
+            assert_eq!(Some(1), smartsheet.get_row(&RowId::from(32)).unwrap().get_row_number());
+        }
+    }
+
+    mod typed_rows {
+        use super::*;
+        use {FromRow, MappingError, RowReader, RowWriter, ToRow};
+        use std::result::Result as StdResult;
+
+        #[derive(Debug, PartialEq)]
+        struct Ticket {
+            key: String,
+            summary: Option<String>,
+        }
+
+        impl FromRow for Ticket {
+            fn from_row(row: &RowReader) -> StdResult<Self, MappingError> {
+                Ok(Ticket {
+                    key: row.get("my_column")?,
+                    summary: row.get(ColumnId::from(22))?,
+                })
+            }
+        }
+
+        impl ToRow for Ticket {
+            fn to_row(&self, row: &mut RowWriter) -> StdResult<(), MappingError> {
+                row.set("my_column", self.key.as_str())?;
+                row.set(ColumnId::from(22), self.summary.clone())
+            }
+        }
+
+        impl FromRow for (RowId, f64) {
+            fn from_row(row: &RowReader) -> StdResult<Self, MappingError> {
+                Ok((row.get_row_id(), row.get("my_column")?))
+            }
+        }
+
+        #[test]
+        fn reads_rows_in_sheet_order() {
+            let smartsheet = create_smartsheet();
+
+            let actual = smartsheet.rows::<Ticket>().unwrap();
+
+            let expected = vec![
+                (RowId::from(31), Ticket { key: "data_21_31".to_string(), summary: Some("data_22_31".to_string()) }),
+                (RowId::from(32), Ticket { key: "data_21_32".to_string(), summary: None }),
+            ];
+            assert_eq!(expected, actual);
+        }
+
+        #[test]
+        fn reports_row_of_invalid_value() {
+            let smartsheet = create_smartsheet();
+
+            let actual = smartsheet.rows::<(RowId, f64)>();
+
+            let expected = Error::RowMapping {
+                row_id: Some(RowId::from(31)),
+                error: MappingError::InvalidValue {
+                    column: "my_column".to_string(),
+                    expected: "number",
+                    actual: CellValue::from("data_21_31"),
+                },
+            };
+            assert_eq!(expected, actual.unwrap_err());
+        }
+
+        #[test]
+        fn writes_rows_as_cells() {
+            let mut smartsheet = create_smartsheet();
+            let mockito = mockito::mock("PUT", "/sheets/11/rows")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .match_body(Matcher::Json(json!([
+                    {
+                        "id": 32,
+                        "cells": [
+                            {
+                                "columnId": 21,
+                                "value": "new_key"
+                            },
+                            {
+                                "columnId": 22,
+                                "value": "new_summary"
+                            }
+                        ]
+                    }
+                ])))
+                .with_body(json!({
+                        "result": [
+                            {
+                                "id": 32,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "new_key"
+                                    },
+                                    {
+                                        "columnId": 22,
+                                        "value": "new_summary"
+                                    }
+                                ]
+                            }
+                        ]
+                    }).to_string())
+                .create();
+            let ticket = Ticket { key: "new_key".to_string(), summary: Some("new_summary".to_string()) };
+
+            let result = smartsheet.push_typed_rows(&[(RowId::from(32), ticket)]);
+
+            mockito.assert();
+            assert!(result.is_ok());
+            assert_cell_value("new_summary", &smartsheet, 22, 32);
+        }
+    }
+
+    mod delete_rows {
+        use super::*;
+
+        #[test]
+        fn removes_rows_from_sheet() {
+            let mut smartsheet = create_smartsheet();
+            let mockito = mockito::mock("DELETE", "/sheets/11/rows?ids=31%2C33&ignoreRowsNotFound=true")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "result": [31]
+                    }).to_string())
+                .create();
+            let row_ids = &[RowId::from(31), RowId::from(33)];
+
+            let result = smartsheet.delete_rows(row_ids, true);
+
+            mockito.assert();
+            assert_eq!(vec![RowId::from(31)], result.unwrap());
+            assert!(smartsheet.get_cell_value(&ColumnId::from(21), &RowId::from(31)).is_none());
             assert_cell_value("data_21_32", &smartsheet, 21, 32);
         }
+
+        #[test]
+        fn splits_many_rows_into_chunks() {
+            let mut smartsheet = create_smartsheet();
+            let mockito = mockito::mock("DELETE", Matcher::Regex(r"^/sheets/11/rows\?ids=".to_string()))
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "result": []
+                    }).to_string())
+                .expect(2)
+                .create();
+            let row_ids: Vec<_> = (0..MAX_ROW_IDS_PER_REQUEST as u64 + 1)
+                .map(RowId::from)
+                .collect();
+
+            let result = smartsheet.delete_rows(&row_ids, false);
+
+            mockito.assert();
+            assert!(result.is_ok());
+        }
+    }
+
+    mod push_rows_partial {
+        use super::*;
+
+        #[test]
+        fn reports_failed_rows() {
+            let mut smartsheet = create_smartsheet();
+            let mockito = mockito::mock("PUT", "/sheets/11/rows?allowPartialSuccess=true")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "message": "PARTIAL_SUCCESS",
+                        "resultCode": 3,
+                        "result": [
+                            {
+                                "id": 32,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "new_data_21_32"
+                                    }
+                                ]
+                            }
+                        ],
+                        "failedItems": [
+                            {
+                                "index": 0,
+                                "rowId": 31,
+                                "error": {
+                                    "errorCode": 1036,
+                                    "message": "Invalid value",
+                                    "refId": "abc123"
+                                }
+                            }
+                        ]
+                    }).to_string())
+                .create();
+            let rows = vec![
+                Row::new(RowId::from(31), Cell::new(ColumnId::from(21), CellValue::from("invalid"))),
+                Row::new(RowId::from(32), Cell::new(ColumnId::from(21), CellValue::from("new_data_21_32"))),
+            ];
+
+            let result = smartsheet.push_rows_partial(rows);
+
+            mockito.assert();
+            let actual = result.unwrap();
+            assert_eq!(&[RowId::from(32)], actual.get_succeeded());
+            assert_eq!(1, actual.get_failed().len());
+            let failure = &actual.get_failed()[0];
+            assert_eq!(0, failure.get_index());
+            assert_eq!(Some(&RowId::from(31)), failure.get_row_id());
+            match *failure.get_error() {
+                Error::Validation(ref error) => {
+                    assert_eq!(None, error.get_status());
+                    assert_eq!(Some("abc123"), error.get_ref_id());
+                },
+                ref error => panic!("Invalid error: '{:?}'", error),
+            }
+            assert_cell_value("data_21_31",     &smartsheet, 21, 31);
+            assert_cell_value("new_data_21_32", &smartsheet, 21, 32);
+        }
+
+        #[test]
+        fn reports_rows_of_failed_chunk() {
+            let mut smartsheet = create_smartsheet();
+            let mock_first = mockito::mock("PUT", "/sheets/11/rows?allowPartialSuccess=true")
+                .match_body(Matcher::Regex(r#"^\[\{"id":0,"#.to_string()))
+                .with_body(json!({
+                        "result": [
+                            {
+                                "id": 31,
+                                "cells": [
+                                    {
+                                        "columnId": 21,
+                                        "value": "new_data_21_31"
+                                    }
+                                ]
+                            }
+                        ]
+                    }).to_string())
+                .create();
+            let mock_second = mockito::mock("PUT", "/sheets/11/rows?allowPartialSuccess=true")
+                .match_body(Matcher::Regex(r#"^\[\{"id":500,"#.to_string()))
+                .with_status(500)
+                .with_body(json!({
+                        "errorCode": 4004,
+                        "message": "Test error"
+                    }).to_string())
+                .create();
+            let rows = (0..MAX_ROWS_PER_REQUEST + 2)
+                .map(|index| Row::new(RowId::from(index as u64), Cell::new(ColumnId::from(21), CellValue::Empty)))
+                .collect();
+
+            let result = smartsheet.push_rows_partial(rows);
+
+            mock_first.assert();
+            mock_second.assert();
+            let actual = result.unwrap();
+            assert_eq!(&[RowId::from(31)], actual.get_succeeded());
+            assert_eq!(2, actual.get_failed().len());
+            let failure = &actual.get_failed()[1];
+            assert_eq!(MAX_ROWS_PER_REQUEST + 1, failure.get_index());
+            assert_eq!(Some(&RowId::from(MAX_ROWS_PER_REQUEST as u64 + 1)), failure.get_row_id());
+            assert_eq!(&Error::ServerError(ApiError::new(500, 4004, "Test error")), failure.get_error());
+            assert_cell_value("new_data_21_31", &smartsheet, 21, 31);
+        }
+    }
+
+    mod add_rows_partial {
+        use super::*;
+        use RowLocation;
+
+        #[test]
+        fn reports_failed_rows_with_input_index() {
+            let mut smartsheet = create_smartsheet();
+            let mockito = mockito::mock("POST", "/sheets/11/rows?allowPartialSuccess=true")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "result": [
+                            {
+                                "id": 33,
+                                "cells": []
+                            }
+                        ],
+                        "failedItems": [
+                            {
+                                "index": 1,
+                                "error": {
+                                    "errorCode": 1036,
+                                    "message": "Invalid value"
+                                }
+                            }
+                        ]
+                    }).to_string())
+                .create();
+            let rows = vec![
+                NewRow::new(RowLocation::ToBottom, vec![]),
+                NewRow::new(RowLocation::ToBottom, vec![Cell::new(ColumnId::from(21), CellValue::from("invalid"))]),
+            ];
+
+            let result = smartsheet.add_rows_partial(rows);
+
+            mockito.assert();
+            let actual = result.unwrap();
+            assert_eq!(&[RowId::from(33)], actual.get_succeeded());
+            assert_eq!(1, actual.get_failed()[0].get_index());
+            assert_eq!(None, actual.get_failed()[0].get_row_id());
+        }
+    }
+
+    mod delete_rows_partial {
+        use super::*;
+
+        #[test]
+        fn reports_missing_rows() {
+            let mut smartsheet = create_smartsheet();
+            let mockito = mockito::mock("DELETE", "/sheets/11/rows?ids=33%2C31&ignoreRowsNotFound=true")
+                .match_header("authorization", "Bearer TEST_TOKEN")
+                .with_body(json!({
+                        "result": [31]
+                    }).to_string())
+                .create();
+            let row_ids = &[RowId::from(33), RowId::from(31)];
+
+            let result = smartsheet.delete_rows_partial(row_ids);
+
+            mockito.assert();
+            let actual = result.unwrap();
+            assert_eq!(&[RowId::from(31)], actual.get_succeeded());
+            assert_eq!(1, actual.get_failed().len());
+            let failure = &actual.get_failed()[0];
+            assert_eq!(0, failure.get_index());
+            assert_eq!(Some(&RowId::from(33)), failure.get_row_id());
+            assert_eq!(&Error::RowNotDeleted(RowId::from(33)), failure.get_error());
+        }
+
+        #[test]
+        fn reports_rows_of_failed_chunk() {
+            let mut smartsheet = create_smartsheet();
+            let row_ids: Vec<_> = (0..MAX_ROW_IDS_PER_REQUEST as u64 + 2)
+                .map(RowId::from)
+                .collect();
+            let mock_first = mockito::mock("DELETE", Matcher::Regex(r"^/sheets/11/rows\?ids=0%2C".to_string()))
+                .with_body(json!({
+                        "result": &row_ids[..MAX_ROW_IDS_PER_REQUEST]
+                    }).to_string())
+                .create();
+            let mock_second = mockito::mock("DELETE", Matcher::Regex(r"^/sheets/11/rows\?ids=200%2C".to_string()))
+                .with_status(500)
+                .with_body(json!({
+                        "errorCode": 4004,
+                        "message": "Test error"
+                    }).to_string())
+                .create();
+
+            let result = smartsheet.delete_rows_partial(&row_ids);
+
+            mock_first.assert();
+            mock_second.assert();
+            let actual = result.unwrap();
+            assert_eq!(&row_ids[..MAX_ROW_IDS_PER_REQUEST], actual.get_succeeded());
+            assert_eq!(2, actual.get_failed().len());
+            let failure = &actual.get_failed()[1];
+            assert_eq!(MAX_ROW_IDS_PER_REQUEST + 1, failure.get_index());
+            assert_eq!(Some(&RowId::from(MAX_ROW_IDS_PER_REQUEST as u64 + 1)), failure.get_row_id());
+            assert_eq!(&Error::ServerError(ApiError::new(500, 4004, "Test error")), failure.get_error());
+        }
+    }
+
+    mod split_into_chunks {
+        use super::*;
+
+        fn create_row(cell_count: usize) -> Row {
+            let cells = (0..cell_count)
+                .map(|index| Cell::new(ColumnId::from(index as u64), CellValue::Empty))
+                .collect();
+            Row::new_with_cells(RowId::from(1), cells)
+        }
+
+        #[test]
+        fn limits_cells_per_chunk() {
+            let rows: Vec<_> = (0..3)
+                .map(|_| create_row(MAX_CELLS_PER_REQUEST / 2))
+                .collect();
+
+            let result = split_into_chunks(&rows, |row| row.get_cells_iter().count());
+
+            let actual: Vec<_> = result.iter()
+                .map(|chunk| chunk.len())
+                .collect();
+            assert_eq!(vec![2, 1], actual);
+        }
+
+        #[test]
+        fn returns_no_chunks_for_no_rows() {
+            let result = split_into_chunks(&[], |_: &Row| 0);
+
+            assert!(result.is_empty());
+        }
     }
 }
@@ -0,0 +1,48 @@
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransferOptions {
+    attachments: bool,
+    discussions: bool,
+    children: bool,
+}
+
+impl TransferOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_attachments(self) -> Self {
+        TransferOptions {
+            attachments: true,
+            ..self
+        }
+    }
+
+    pub fn with_discussions(self) -> Self {
+        TransferOptions {
+            discussions: true,
+            ..self
+        }
+    }
+
+    // Moved rows always take their children along, so this affects only copying
+    pub fn with_children(self) -> Self {
+        TransferOptions {
+            children: true,
+            ..self
+        }
+    }
+
+    crate fn get_include(&self, allow_children: bool) -> String {
+        let mut include = Vec::new();
+        if self.attachments {
+            include.push("attachments");
+        }
+        if self.children && allow_children {
+            include.push("children");
+        }
+        if self.discussions {
+            include.push("discussions");
+        }
+        include.join(",")
+    }
+}
@@ -0,0 +1,19 @@
+use std::fmt::{Display, Error as FmtError, Formatter};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct WorkspaceId {
+    id: u64,
+}
+
+impl From<u64> for WorkspaceId {
+    fn from(id: u64) -> Self {
+        WorkspaceId { id }
+    }
+}
+
+impl Display for WorkspaceId {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        self.id.fmt(f)
+    }
+}